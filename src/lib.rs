@@ -1,9 +1,33 @@
 //! Library containing a rectangle type.
+//!
+//! # Features
+//!
+//! - `glam`: adds conversions between [`Rect`] and `glam::IVec2` pairs.
+//! - `rand`: adds `Rect::random_point`.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Rect`], and adds
+//!   `RectArray` and `serde_array` for serializing rects as arrays.
 
 use point::Point;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
 
 /// A rectangle.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+///
+/// Co-ords are `i32` to match [`Point`], so every tile of the rect, including
+/// [`Rect::right`] and [`Rect::bottom`], must fit in an `i32`. For larger worlds,
+/// use [`RectI64`].
+///
+/// Rects are ordered by `top`, then `left`, then `wid`, then `hgt`. This gives a
+/// consistent order for sorted collections, but has nothing to do with size, so
+/// sorting rects does not put the largest first; see [`Rect::area`] for that.
+///
+/// The default rect is [`Rect::EMPTY`], which has no width or height and so
+/// contains no tiles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     /// Largest y co-ord of the rect.
     pub top: i32,
@@ -15,9 +39,102 @@ pub struct Rect {
     pub hgt: i32,
 }
 
+/// Where one rect lies relative to another, as returned by [`Rect::relative_position`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RectRelation {
+    /// The rects share at least one tile.
+    Overlapping,
+    /// Entirely above, sharing some columns.
+    Above,
+    /// Entirely below, sharing some columns.
+    Below,
+    /// Entirely to the left, sharing some rows.
+    Left,
+    /// Entirely to the right, sharing some rows.
+    Right,
+    /// Entirely above and to the left, sharing no rows or columns.
+    AboveLeft,
+    /// Entirely above and to the right, sharing no rows or columns.
+    AboveRight,
+    /// Entirely below and to the left, sharing no rows or columns.
+    BelowLeft,
+    /// Entirely below and to the right, sharing no rows or columns.
+    BelowRight,
+}
+
+/// Where to place a rect within a container, as used by [`Rect::align_to`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Against the top and left edges.
+    TopLeft,
+    /// Against the top edge, centred horizontally.
+    Top,
+    /// Against the top and right edges.
+    TopRight,
+    /// Against the left edge, centred vertically.
+    Left,
+    /// Centred both ways.
+    Center,
+    /// Against the right edge, centred vertically.
+    Right,
+    /// Against the bottom and left edges.
+    BottomLeft,
+    /// Against the bottom edge, centred horizontally.
+    Bottom,
+    /// Against the bottom and right edges.
+    BottomRight,
+}
+
+/// One of the four corners of a rect, as used by [`Rect::corner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+/// One of the four sides of a rect, as used by [`Rect::side_tiles`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The top row.
+    Top,
+    /// The bottom row.
+    Bottom,
+    /// The leftmost column.
+    Left,
+    /// The rightmost column.
+    Right,
+}
+
 impl Rect {
+    /// A rect at the origin with no width or height. It contains no tiles, so
+    /// growing it with [`Rect::grow_to_include`] gives a 1x1 rect at the new point.
+    pub const EMPTY: Self = Self::new(0, 0, 0, 0);
+
+    /// A 1x1 rect covering only the origin.
+    pub const UNIT: Self = Self::new(0, 0, 1, 1);
+
+    /// Returns [`Rect::EMPTY`], which is also the default rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert!(Rect::empty().is_empty());
+    /// assert_eq!(Rect::empty(), Rect::default());
+    /// ```
+    pub const fn empty() -> Self {
+        Self::EMPTY
+    }
+
     /// Create a new rectangle.
-    pub fn new(left: i32, top: i32, wid: i32, hgt: i32) -> Self {
+    pub const fn new(left: i32, top: i32, wid: i32, hgt: i32) -> Self {
         Self {
             top,
             left,
@@ -26,6 +143,100 @@ impl Rect {
         }
     }
 
+    /// Create a new rectangle, or None if the width or height is not positive, or
+    /// if [`Rect::right`] or [`Rect::bottom`] would not fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::checked_new(1, 5, 3, 4), Some(Rect::new(1, 5, 3, 4)));
+    /// assert_eq!(Rect::checked_new(1, 5, 0, 4), None);
+    /// assert_eq!(Rect::checked_new(i32::MAX, 5, 2, 4), None);
+    /// ```
+    pub const fn checked_new(left: i32, top: i32, wid: i32, hgt: i32) -> Option<Self> {
+        if wid <= 0 || hgt <= 0 {
+            return None;
+        }
+
+        if left.checked_add(wid - 1).is_none() || top.checked_sub(hgt - 1).is_none() {
+            return None;
+        }
+
+        Some(Self::new(left, top, wid, hgt))
+    }
+
+    /// Create the smallest rectangle containing both points, which may be any
+    /// pair of opposite corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::from_corners(Point::new(3, -1), Point::new(1, 1));
+    ///
+    /// assert_eq!(rect, Rect::new(1, 1, 3, 3));
+    /// ```
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self::new(
+            a.x.min(b.x),
+            a.y.max(b.y),
+            (a.x - b.x).abs() + 1,
+            (a.y - b.y).abs() + 1,
+        )
+    }
+
+    /// Create the smallest rectangle containing every given point,
+    /// or None if there are no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let points = [Point::new(1, 0), Point::new(-2, 3), Point::new(0, -1)];
+    ///
+    /// assert_eq!(Rect::bounding_box(points), Some(Rect::new(-2, 3, 4, 5)));
+    /// assert_eq!(Rect::bounding_box([]), None);
+    /// ```
+    pub fn bounding_box(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut rect = Self::new(first.x, first.y, 1, 1);
+
+        for pos in points {
+            rect.grow_to_include(pos);
+        }
+
+        Some(rect)
+    }
+
+    /// Create the smallest rectangle containing every given rect, or None if there
+    /// are none. Empty rects contain no tiles, so they are skipped, and None is also
+    /// returned if every rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rects = [Rect::new(0, 4, 3, 2), Rect::new(5, 1, 2, 2), Rect::new(9, 9, 0, 0)];
+    ///
+    /// assert_eq!(Rect::bounding_box_of(&rects), Some(Rect::new(0, 4, 7, 5)));
+    /// assert_eq!(Rect::bounding_box_of(&[]), None);
+    /// ```
+    pub fn bounding_box_of(rects: &[Self]) -> Option<Self> {
+        rects
+            .iter()
+            .filter(|rect| !rect.is_empty())
+            .copied()
+            .reduce(|acc, rect| acc.union(&rect))
+    }
+
     /// Rightmost x co-ord of the rect.
     ///
     /// # Examples
@@ -46,8 +257,8 @@ impl Rect {
 	///
     /// assert_eq!(rect.right(), 4);
     /// ```
-    pub fn right(&self) -> i32 {
-        self.left + self.wid - 1
+    pub const fn right(&self) -> i32 {
+        self.left + (self.wid - 1)
     }
 
     /// Lowest y co-ord of the rect.
@@ -70,11 +281,12 @@ impl Rect {
     ///
     /// assert_eq!(rect.bottom(), -1);
     /// ```
-    pub fn bottom(&self) -> i32 {
-        self.top - self.hgt + 1
+    pub const fn bottom(&self) -> i32 {
+        self.top - (self.hgt - 1)
     }
 
-    /// Returns true if the rect overlaps other.
+    /// Returns true if the rect overlaps other. Empty rects contain no tiles, so
+    /// never overlap anything.
     ///
     /// # Examples
     ///
@@ -105,198 +317,208 @@ impl Rect {
 	/// assert!(!rect3.overlaps(&rect2));
     /// ```
     pub fn overlaps(&self, other: &Self) -> bool {
-        self.left <= other.right()
+        !self.is_empty()
+            && !other.is_empty()
+            && self.left <= other.right()
             && self.right() >= other.left
             && self.top >= other.bottom()
             && self.bottom() <= other.top
     }
 
-    /// Returns the top left corner as a point.
+    /// Returns true if the rect overlaps any of the others.
     ///
     /// # Examples
     ///
     /// ```
-    /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let rect = Rect::new(2, 1, 4, 3);
-    ///
-    /// // The above rectangle, below:
-	/// // 'O' is the origin.
-    /// //
-    /// // Corner in question
-	/// //   |
-	/// //   v
-    /// //   +--+
-    /// //   |  |
-    /// //   +--+
-	/// // O 
+    /// let rooms = [Rect::new(0, 7, 4, 3), Rect::new(10, 2, 3, 3)];
     ///
-    /// assert_eq!(rect.top_left(), Point::new(2, 1));
+    /// assert!(Rect::new(3, 6, 5, 5).any_overlaps(&rooms));
+    /// assert!(!Rect::new(5, 9, 3, 3).any_overlaps(&rooms));
     /// ```
-    pub fn top_left(&self) -> Point {
-        Point::new(self.left, self.top)
+    pub fn any_overlaps(&self, others: &[Self]) -> bool {
+        others.iter().any(|other| self.overlaps(other))
     }
 
-    /// Returns an iterator over each point on the edge of the rectangle.
-	/// Iterates in a clockwise direction.
-	///
-	/// # Examples
+    /// Returns the index of every one of the others that the rect overlaps, in order.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let mut rect = Rect::new(1, 1, 3, 3);
-    ///
-    /// // The above rectangle, below:
-    /// //
-    /// //  +-+
-    /// //  | |
-    /// //  +-+
-    ///
-    /// let expected = vec![
-	///	    Point::new(1, 1),
-	///	    Point::new(2, 1),
-	///     Point::new(3, 1),
-	///     Point::new(3, 0),
-	///     Point::new(3, -1),
-	///     Point::new(2, -1),
-	///     Point::new(1, -1),
-	///     Point::new(1, 0)
-	/// ];  
+    /// let rooms = [Rect::new(0, 7, 4, 3), Rect::new(10, 2, 3, 3), Rect::new(6, 1, 2, 2)];
     ///
-    /// assert_eq!(rect.edges().collect::<Vec<_>>(), expected);
+    /// assert_eq!(Rect::new(3, 6, 5, 6).overlapping_indices(&rooms), vec![0, 2]);
     /// ```
-    pub fn edges(&self) -> EdgeIter {
-        EdgeIter::from(self)
+    pub fn overlapping_indices(&self, others: &[Self]) -> Vec<usize> {
+        others
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| self.overlaps(other))
+            .map(|(i, _)| i)
+            .collect()
     }
 
-    /// Returns each corner of the rect.
-	///
+    /// Returns the region shared by both rects, or None if they do not overlap.
+    /// Tiles on the edges of either rect count as part of the region.
+    ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let mut rect = Rect::new(1, 1, 3, 5);
+    /// let rect1 = Rect::new(0, 7, 4, 3);
+    /// let rect2 = Rect::new(3, 6, 5, 5);
+    /// let rect3 = Rect::new(10, 2, 3, 3);
     ///
-    /// // The above rectangle, below:
-	/// // 'O' is the origin.
+    /// // The above rectangles, below:
+    /// // '!' represents where an overlap occurs is.
+    /// // 'O' is the origin.
     /// //
-    /// //  +-+
-    /// // O| |
-    /// //  | |
-    /// //  | |
-    /// //  +-+
-	/// // 
-    ///
-    /// let expected = [Point::new(1, 1), Point::new(3, 1), Point::new(1, -3), Point::new(3, -3)];  
+    /// // +--+
+    /// // |1 !---+
+    /// // +--!   |
+    /// //    | 2 |
+    /// //    |   |
+    /// //    +---+  +-+
+    /// //           |3|
+    /// // O         +-+
     ///
-    /// assert_eq!(rect.corners(), expected);
+    /// assert_eq!(rect1.intersection(&rect2), Some(Rect::new(3, 6, 1, 2)));
+    /// assert_eq!(rect2.intersection(&rect1), Some(Rect::new(3, 6, 1, 2)));
+    /// assert_eq!(rect3.intersection(&rect1), None);
     /// ```
-    pub fn corners(&self) -> [Point; 4] {
-        let left = self.left;
-        let right = self.right();
-        let top = self.top;
-        let bottom = self.bottom();
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let left = self.left.max(other.left);
+        let right = self.right().min(other.right());
+        let top = self.top.min(other.top);
+        let bottom = self.bottom().max(other.bottom());
 
-        [
-            Point::new(left, top),
-            Point::new(right, top),
-            Point::new(left, bottom),
-            Point::new(right, bottom),
-        ]
+        if left > right || bottom > top {
+            return None;
+        }
+
+        Some(Self::new(left, top, right - left + 1, top - bottom + 1))
     }
 
-    /// Increases the size of the rectangle in the given direction.
+    /// Returns the number of tiles shared by both rects, or 0 if they do not overlap.
     ///
     /// # Examples
     ///
     /// ```
-    /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let mut rect = Rect::new(1, 1, 3, 5);
+    /// let rect1 = Rect::new(0, 7, 4, 3);
+    /// let rect2 = Rect::new(3, 6, 5, 5);
     ///
-    /// // The above rectangle, below:
-	/// // 'O' is the origin.
-    /// //
-    /// //  +-+
-    /// // O| |
-    /// //  | |
-    /// //  | |
-    /// //  +-+
-    /// // 
-	///
-    /// let transformed = Rect::new(1, 1, 4, 5);
-	/// rect.expand(Point::new(1, 0));
+    /// assert_eq!(rect1.overlap_area(&rect2), 2);
+    /// ```
+    pub fn overlap_area(&self, other: &Self) -> u32 {
+        self.intersection(other).map_or(0, |shared| shared.area())
+    }
+
+    /// Returns the intersection over union of the rects: the number of tiles they
+    /// share divided by the number of tiles covered by either. This is 1.0 for
+    /// identical rects and 0.0 for rects that do not overlap, including when both
+    /// are empty.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(rect, transformed);
-	///
-    /// // After transformation:
-    /// //
-    /// //  +--+
-    /// //  |  |
-    /// //  |  |
-    /// //  |  |
-    /// //  +--+
     /// ```
-    pub fn expand(&mut self, dir: Point) {
-        self.wid += dir.x.abs();
+    /// use rect::Rect;
+    ///
+    /// let rect1 = Rect::new(0, 1, 4, 2);
+    /// let rect2 = Rect::new(2, 1, 4, 2);
+    ///
+    /// // 4 shared tiles out of 12 covered.
+    /// assert_eq!(rect1.iou(&rect2), 4.0 / 12.0);
+    /// ```
+    pub fn iou(&self, other: &Self) -> f64 {
+        let shared = self.overlap_area(other) as f64;
 
-        if dir.x < 0 {
-            self.left += dir.x;
+        if shared == 0.0 {
+            return 0.0;
         }
 
-        self.hgt += dir.y.abs();
+        shared / (self.area() as f64 + other.area() as f64 - shared)
+    }
 
-        if dir.y > 0 {
-            self.top += dir.y;
+    /// Returns the fraction of the rect's tiles that are also in other, from 0.0
+    /// when they do not overlap to 1.0 when other contains the whole rect. Unlike
+    /// [`Rect::iou`], this ignores how much of other lies outside the rect. Empty
+    /// rects give 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 1, 4, 2);
+    ///
+    /// assert_eq!(rect.coverage_by(&Rect::new(2, 5, 9, 9)), 0.5);
+    /// assert_eq!(rect.coverage_by(&Rect::new(-1, 2, 6, 4)), 1.0);
+    /// ```
+    pub fn coverage_by(&self, other: &Self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
         }
+
+        self.overlap_area(other) as f64 / self.area() as f64
     }
 
-    /// Checks whether the given position is within or on the rectangle's boundaries.
+    /// Returns where other lies relative to the rect. Other is only diagonal to the
+    /// rect, such as [`RectRelation::AboveLeft`], if it shares none of the rect's rows
+    /// or columns.
     ///
     /// # Examples
     ///
     /// ```
-    /// use point::Point;
-    /// use rect::Rect;
+    /// use rect::{Rect, RectRelation};
     ///
-    /// let rect = Rect::new(0, 0, 3, 5);
+    /// let rect1 = Rect::new(0, 4, 3, 3);
+    /// let rect2 = Rect::new(2, 7, 4, 2);
+    /// let rect3 = Rect::new(5, 1, 2, 2);
     ///
-    /// // The above rectangle, below:
-	/// // 'O' is the origin.
-	/// //
-    /// // O-+
-    /// // | |
-    /// // | |
+    /// // The above rectangles, below:
+    /// // 'O' is the origin.
+    /// //
+    /// //   +--+
+    /// //   +--+
+    /// //
+    /// // +-+
     /// // | |
     /// // +-+
+    /// //      ++
+    /// // O    ++
     ///
-    /// assert!(rect.contains(Point::new(1, -3)));
-    /// assert!(rect.contains(Point::new(1, 0)));
-    /// assert!(!rect.contains(Point::new(-1, 0)));
+    /// assert_eq!(rect1.relative_position(&rect2), RectRelation::Above);
+    /// assert_eq!(rect1.relative_position(&rect3), RectRelation::BelowRight);
+    /// assert_eq!(rect3.relative_position(&rect1), RectRelation::AboveLeft);
+    /// assert_eq!(rect3.relative_position(&rect2), RectRelation::Above);
     /// ```
-    pub fn contains(&self, pos: Point) -> bool {
-        self.left <= pos.x && self.right() >= pos.x && self.top >= pos.y && self.bottom() <= pos.y
-    }
-
-    /// Return an iterator over all positions contained
-    /// within the rect, including the edges.
-    #[inline]
-    pub fn cells(&self) -> InteriorIter {
-        InteriorIter::from(*self)
-    }
+    pub fn relative_position(&self, other: &Self) -> RectRelation {
+        let above = other.bottom() > self.top;
+        let below = other.top < self.bottom();
+        let left = other.right() < self.left;
+        let right = other.left > self.right();
 
-    /// Return all positions contained within the rect, excluding the edges.
-    pub fn inner_cells(&self) -> InteriorIter {
-        InteriorIter::from(Rect::new(self.left + 1, self.top - 1, self.wid - 2, self.hgt - 2))
+        match (above, below, left, right) {
+            (true, _, true, _) => RectRelation::AboveLeft,
+            (true, _, _, true) => RectRelation::AboveRight,
+            (_, true, true, _) => RectRelation::BelowLeft,
+            (_, true, _, true) => RectRelation::BelowRight,
+            (true, ..) => RectRelation::Above,
+            (_, true, ..) => RectRelation::Below,
+            (_, _, true, _) => RectRelation::Left,
+            (.., true) => RectRelation::Right,
+            _ => RectRelation::Overlapping,
+        }
     }
 
-    /// Returns the area of the rectangle.
+    /// Returns the smallest rect containing both rects.
     ///
     /// # Examples
     ///
@@ -304,32 +526,36 @@ impl Rect {
     /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let rect = Rect::new(0, 5, 3, 5);
+    /// let rect1 = Rect::new(0, 4, 3, 2);
+    /// let rect2 = Rect::new(5, 1, 2, 2);
     ///
-    /// // The above rectangle, below:
-	/// // 'O' is the origin.
-	/// //
+    /// // The above rectangles, below:
+    /// // 'O' is the origin.
+    /// //
     /// // +-+
-    /// // | |
-    /// // | |
-    /// // | |
-    /// // O-+
+    /// // +-+
+    /// //
+    /// //
+    /// //      ++
+    /// // O    ++
     ///
-    /// assert_eq!(rect.area(), 15);
+    /// let union = rect1.union(&rect2);
+    ///
+    /// assert_eq!(union, Rect::new(0, 4, 7, 5));
+    /// assert!(union.area() >= rect1.area());
+    /// assert!(union.area() >= rect2.area());
     /// ```
-    pub fn area(&self) -> u32 {
-        (self.wid * self.hgt).unsigned_abs()
-    }
+    pub fn union(&self, other: &Self) -> Self {
+        let left = self.left.min(other.left);
+        let right = self.right().max(other.right());
+        let top = self.top.max(other.top);
+        let bottom = self.bottom().min(other.bottom());
 
-    /// Relocates the rect's top left corner to the given position.
-    pub fn move_to(&mut self, pos: Point) {
-        self.left = pos.x;
-        self.top = pos.y;
+        Self::new(left, top, right - left + 1, top - bottom + 1)
     }
 
-    /// Centres the rect on the given position. When it is not possible to centre
-	/// exactly on the provided co-ordinates, the new centre will be to the right of
-	/// and/or below the true centre.
+    /// Returns true if the rect shares part of an edge with other without
+    /// overlapping it. Rects that only meet diagonally at a corner do not touch.
     ///
     /// # Examples
     ///
@@ -337,166 +563,5828 @@ impl Rect {
     /// use point::Point;
     /// use rect::Rect;
     ///
-    /// let mut rect = Rect::new(0, 4, 4, 4);
-	/// rect.centre_on(Point::new(4, 4));
+    /// let rect1 = Rect::new(0, 4, 3, 3);
+    /// let rect2 = Rect::new(3, 3, 2, 4);
+    /// let rect3 = Rect::new(5, -1, 2, 2);
     ///
-    /// // The above rectangle (before centring), below:
-	/// // 'C' is where the centre would be placed.
-	/// //     C
-    /// // +--+
-    /// // |  |
-    /// // |  |
-    /// // O--+
+    /// // The above rectangles, below:
+    /// // 'O' is the origin.
     /// //
-	/// // After centring:
-	/// //              
-	/// //   +--+          
-	/// //   |  |          
-	/// //   | C|        
-	/// //   +--+      
-	/// //         
-	/// //        
-	/// // O   
-	/// //
-	/// 
-    /// assert_eq!(rect.top_left(), Point::new(2, 6));
+    /// // +-+
+    /// // | |++
+    /// // +-+||
+    /// //    ||
+    /// // O  ++
+    /// //      ++
+    /// //      ++
+    ///
+    /// assert!(rect1.touches(&rect2));
+    /// assert!(!rect2.touches(&rect3));
+    /// assert!(!rect1.touches(&rect3));
     /// ```
-    pub fn centre_on(&mut self, centre: Point) {
-        self.move_to(centre + Point::new(-self.wid / 2, self.hgt / 2));
+    pub fn touches(&self, other: &Self) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+
+        let x_overlap = self.left <= other.right() && self.right() >= other.left;
+        let y_overlap = self.top >= other.bottom() && self.bottom() <= other.top;
+        let x_adjacent = self.right() + 1 == other.left || other.right() + 1 == self.left;
+        let y_adjacent = self.bottom() - 1 == other.top || other.bottom() - 1 == self.top;
+
+        (x_adjacent && y_overlap) || (y_adjacent && x_overlap)
+    }
+
+    /// Returns the two rects merged into one, if they touch along a whole side.
+    /// That is, they must share the same `top` and height and sit side by side, or
+    /// share the same `left` and width and sit one above the other. Returns None
+    /// otherwise, including when either rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect1 = Rect::new(0, 2, 3, 2);
+    /// let rect2 = Rect::new(3, 2, 4, 2);
+    /// let rect3 = Rect::new(3, 3, 4, 2);
+    ///
+    /// // rect1 and rect3, below:
+    /// // 'O' is the origin.
+    /// //
+    /// //    +--+
+    /// // +-++--+
+    /// // +-+
+    /// // O
+    ///
+    /// assert_eq!(rect1.merge_if_aligned(&rect2), Some(Rect::new(0, 2, 7, 2)));
+    /// assert_eq!(rect1.merge_if_aligned(&rect3), None);
+    /// ```
+    pub fn merge_if_aligned(&self, other: &Self) -> Option<Self> {
+        if !self.touches(other) {
+            return None;
+        }
+
+        let same_rows = self.top == other.top && self.hgt == other.hgt;
+        let same_columns = self.left == other.left && self.wid == other.wid;
+
+        if same_rows || same_columns {
+            Some(self.union(other))
+        } else {
+            None
+        }
+    }
+
+    /// Returns up to four non-overlapping rects covering the parts of the rect not
+    /// covered by other. Any part above or below other spans the full width of the
+    /// rect, with the parts to the left and right of other filling in between.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    /// let hole = Rect::new(1, 3, 3, 3);
+    ///
+    /// let expected = vec![
+    ///     Rect::new(0, 4, 5, 1),
+    ///     Rect::new(0, 0, 5, 1),
+    ///     Rect::new(0, 3, 1, 3),
+    ///     Rect::new(4, 3, 1, 3),
+    /// ];
+    ///
+    /// assert_eq!(rect.difference(&hole), expected);
+    /// assert_eq!(rect.difference(&Rect::new(10, 10, 1, 1)), vec![rect]);
+    /// assert!(hole.difference(&rect).is_empty());
+    /// ```
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(shared) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let mut parts = Vec::with_capacity(4);
+
+        if shared.top < self.top {
+            parts.push(Self::new(self.left, self.top, self.wid, self.top - shared.top));
+        }
+
+        if shared.bottom() > self.bottom() {
+            parts.push(Self::new(
+                self.left,
+                shared.bottom() - 1,
+                self.wid,
+                shared.bottom() - self.bottom(),
+            ));
+        }
+
+        if shared.left > self.left {
+            parts.push(Self::new(self.left, shared.top, shared.left - self.left, shared.hgt));
+        }
+
+        if shared.right() < self.right() {
+            parts.push(Self::new(
+                shared.right() + 1,
+                shared.top,
+                self.right() - shared.right(),
+                shared.hgt,
+            ));
+        }
+
+        parts
+    }
+
+    /// Returns the top left corner as a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(2, 1, 4, 3);
+    ///
+    /// // The above rectangle, below:
+	/// // 'O' is the origin.
+    /// //
+    /// // Corner in question
+	/// //   |
+	/// //   v
+    /// //   +--+
+    /// //   |  |
+    /// //   +--+
+	/// // O 
+    ///
+    /// assert_eq!(rect.top_left(), Point::new(2, 1));
+    /// ```
+    pub fn top_left(&self) -> Point {
+        Point::new(self.left, self.top)
+    }
+
+    /// Returns the top right corner as a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(2, 1, 4, 3);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// //
+    /// // Corner in question
+    /// //      |
+    /// //      v
+    /// //   +--+
+    /// // O |  |
+    /// //   +--+
+    ///
+    /// assert_eq!(rect.top_right(), Point::new(5, 1));
+    /// ```
+    pub fn top_right(&self) -> Point {
+        Point::new(self.right(), self.top)
+    }
+
+    /// Returns the bottom left corner as a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(2, 1, 4, 3);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// //
+    /// //   +--+
+    /// // O |  |
+    /// //   +--+
+    /// //   ^
+    /// //   |
+    /// // Corner in question
+    ///
+    /// assert_eq!(rect.bottom_left(), Point::new(2, -1));
+    /// ```
+    pub fn bottom_left(&self) -> Point {
+        Point::new(self.left, self.bottom())
+    }
+
+    /// Returns the bottom right corner as a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(2, 1, 4, 3);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// //
+    /// //   +--+
+    /// // O |  |
+    /// //   +--+
+    /// //      ^
+    /// //      |
+    /// // Corner in question
+    ///
+    /// assert_eq!(rect.bottom_right(), Point::new(5, -1));
+    /// ```
+    pub fn bottom_right(&self) -> Point {
+        Point::new(self.right(), self.bottom())
+    }
+
+    /// Returns an iterator over each point on the edge of the rectangle.
+	/// Iterates in a clockwise direction, starting from the top left corner and
+	/// going right along the top edge. The start is not repeated at the end.
+	///
+	/// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(1, 1, 3, 3);
+    ///
+    /// // The above rectangle, below:
+    /// //
+    /// //  +-+
+    /// //  | |
+    /// //  +-+
+    ///
+    /// let expected = vec![
+	///	    Point::new(1, 1),
+	///	    Point::new(2, 1),
+	///     Point::new(3, 1),
+	///     Point::new(3, 0),
+	///     Point::new(3, -1),
+	///     Point::new(2, -1),
+	///     Point::new(1, -1),
+	///     Point::new(1, 0)
+	/// ];  
+    ///
+    /// assert_eq!(rect.edges().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn edges(&self) -> EdgeIter {
+        EdgeIter::from(self)
+    }
+
+    /// Returns each corner of the rect, in the order top left, top right, bottom left,
+    /// bottom right.
+	///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(1, 1, 3, 5);
+    ///
+    /// // The above rectangle, below:
+	/// // 'O' is the origin.
+    /// //
+    /// //  +-+
+    /// // O| |
+    /// //  | |
+    /// //  | |
+    /// //  +-+
+	/// // 
+    ///
+    /// let expected = [Point::new(1, 1), Point::new(3, 1), Point::new(1, -3), Point::new(3, -3)];  
+    ///
+    /// assert_eq!(rect.corners(), expected);
+    /// ```
+    pub fn corners(&self) -> [Point; 4] {
+        let left = self.left;
+        let right = self.right();
+        let top = self.top;
+        let bottom = self.bottom();
+
+        [
+            Point::new(left, top),
+            Point::new(right, top),
+            Point::new(left, bottom),
+            Point::new(right, bottom),
+        ]
+    }
+
+    /// Returns the given corner of the rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::{Corner, Rect};
+    ///
+    /// let rect = Rect::new(1, 1, 3, 5);
+    ///
+    /// assert_eq!(rect.corner(Corner::BottomLeft), Point::new(1, -3));
+    /// ```
+    pub fn corner(&self, corner: Corner) -> Point {
+        match corner {
+            Corner::TopLeft => self.top_left(),
+            Corner::TopRight => self.top_right(),
+            Corner::BottomLeft => self.bottom_left(),
+            Corner::BottomRight => self.bottom_right(),
+        }
+    }
+
+    /// Returns which corner of the rect is nearest to pos by euclidean distance.
+    /// Ties are broken in the order of [`Rect::corners`], so the top left corner
+    /// wins over the others and the bottom right corner never wins a tie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::{Corner, Rect};
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    ///
+    /// assert_eq!(rect.nearest_corner_kind(Point::new(5, -1)), Corner::BottomRight);
+    /// assert_eq!(rect.nearest_corner_kind(Point::new(2, 2)), Corner::TopLeft);
+    /// ```
+    pub fn nearest_corner_kind(&self, pos: Point) -> Corner {
+        let kinds = [Corner::TopLeft, Corner::TopRight, Corner::BottomLeft, Corner::BottomRight];
+        let dist = |corner: Point| {
+            let dx = (corner.x as i64 - pos.x as i64).abs();
+            let dy = (corner.y as i64 - pos.y as i64).abs();
+
+            dx * dx + dy * dy
+        };
+
+        let mut nearest = 0;
+        let corners = self.corners();
+
+        for i in 1..corners.len() {
+            if dist(corners[i]) < dist(corners[nearest]) {
+                nearest = i;
+            }
+        }
+
+        kinds[nearest]
+    }
+
+    /// Returns the corner of the rect nearest to pos by euclidean distance, breaking
+    /// ties as [`Rect::nearest_corner_kind`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    ///
+    /// assert_eq!(rect.nearest_corner(Point::new(3, 3)), Point::new(4, 4));
+    /// ```
+    pub fn nearest_corner(&self, pos: Point) -> Point {
+        self.corner(self.nearest_corner_kind(pos))
+    }
+
+    /// Increases the size of the rectangle in the given direction.
+    ///
+    /// As y increases upwards, a positive `dir.y` moves the top edge up and a
+    /// negative `dir.y` moves the bottom edge down. Likewise a positive `dir.x`
+    /// moves the right edge right and a negative `dir.x` moves the left edge left.
+    /// The opposite edge on each axis stays put.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(1, 1, 3, 5);
+    ///
+    /// // The above rectangle, below:
+	/// // 'O' is the origin.
+    /// //
+    /// //  +-+
+    /// // O| |
+    /// //  | |
+    /// //  | |
+    /// //  +-+
+    /// // 
+	///
+    /// let transformed = Rect::new(1, 1, 4, 5);
+	/// rect.expand(Point::new(1, 0));
+    ///
+    /// assert_eq!(rect, transformed);
+	///
+    /// // After transformation:
+    /// //
+    /// //  +--+
+    /// //  |  |
+    /// //  |  |
+    /// //  |  |
+    /// //  +--+
+    /// ```
+    pub fn expand(&mut self, dir: Point) {
+        self.wid += dir.x.abs();
+
+        if dir.x < 0 {
+            self.left += dir.x;
+        }
+
+        self.hgt += dir.y.abs();
+
+        if dir.y > 0 {
+            self.top += dir.y;
+        }
+    }
+
+    /// Returns a copy of the rect increased in size in the given direction,
+    /// as with [`Rect::expand`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 3, 5);
+    /// let bigger = rect.expanded(Point::new(1, 0));
+    ///
+    /// assert_eq!(bigger, Rect::new(1, 1, 4, 5));
+    /// assert_eq!(rect, Rect::new(1, 1, 3, 5));
+    /// ```
+    pub fn expanded(&self, dir: Point) -> Self {
+        let mut rect = *self;
+        rect.expand(dir);
+        rect
+    }
+
+    /// Decreases the size of the rectangle, undoing an [`Rect::expand`] in the same
+    /// direction. A positive `dir.y` moves the top edge down and a negative `dir.y`
+    /// moves the bottom edge up, with x behaving likewise for the right and left edges.
+    ///
+    /// If shrinking would make a dimension negative, it is clamped to zero instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(1, 1, 4, 5);
+    /// rect.shrink(Point::new(1, 0));
+    ///
+    /// assert_eq!(rect, Rect::new(1, 1, 3, 5));
+    ///
+    /// rect.shrink(Point::new(0, -10));
+    ///
+    /// assert_eq!(rect, Rect::new(1, 1, 3, 0));
+    /// ```
+    pub fn shrink(&mut self, dir: Point) {
+        let dx = dir.x.abs().min(self.wid.max(0));
+        self.wid -= dx;
+
+        if dir.x < 0 {
+            self.left += dx;
+        }
+
+        let dy = dir.y.abs().min(self.hgt.max(0));
+        self.hgt -= dy;
+
+        if dir.y > 0 {
+            self.top -= dy;
+        }
+    }
+
+    /// Returns a copy of the rect shrunk by margin tiles on every side, so the width
+    /// and height each drop by `2 * margin`. A negative margin grows the rect instead.
+    ///
+    /// If the margin is too large for the rect, the width and/or height are clamped
+    /// to zero, leaving an empty rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    ///
+    /// assert_eq!(rect.inset(1), Rect::new(1, 3, 3, 3));
+    /// assert_eq!(rect.inset(-1), rect.outset(1));
+    /// ```
+    pub fn inset(&self, margin: i32) -> Self {
+        Self::new(
+            self.left + margin,
+            self.top - margin,
+            (self.wid - 2 * margin).max(0),
+            (self.hgt - 2 * margin).max(0),
+        )
+    }
+
+    /// Returns a copy of the rect grown by margin tiles on every side.
+    /// This is the same as calling [`Rect::inset`] with `-margin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 3, 3, 3);
+    ///
+    /// assert_eq!(rect.outset(1), Rect::new(0, 4, 5, 5));
+    /// ```
+    pub fn outset(&self, margin: i32) -> Self {
+        self.inset(-margin)
+    }
+
+    /// Grows each side of the rect outwards by its own number of tiles. Negative
+    /// values move that side inwards instead.
+    ///
+    /// `left` is moved left by the left margin and `top` up by the top margin. The
+    /// width grows by the left and right margins together, and the height by the
+    /// top and bottom margins. As with [`Rect::inset`], a width or height shrunk
+    /// below zero is clamped to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(2, 2, 3, 3);
+    /// rect.expand_each(1, 0, 2, -1);
+    ///
+    /// assert_eq!(rect, Rect::new(1, 2, 6, 2));
+    /// ```
+    pub fn expand_each(&mut self, left: i32, top: i32, right: i32, bottom: i32) {
+        self.left -= left;
+        self.top += top;
+        self.wid = (self.wid + left + right).max(0);
+        self.hgt = (self.hgt + top + bottom).max(0);
+    }
+
+    /// Grows the rect by dx tiles on both the left and right and by dy tiles on
+    /// both the top and bottom, so the width increases by `2 * dx` and the height by
+    /// `2 * dy`. Negative values shrink the rect instead.
+    ///
+    /// As both sides move equally, [`Rect::center`] stays exactly where it was, with
+    /// no rounding, unless a shrink takes the width or height below zero. That
+    /// dimension is then clamped to zero as in [`Rect::expand_each`], and the centre
+    /// moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(0, 4, 4, 5);
+    /// let centre = rect.center();
+    /// rect.grow_centered(1, 3);
+    ///
+    /// assert_eq!(rect, Rect::new(-1, 7, 6, 11));
+    /// assert_eq!(rect.center(), centre);
+    ///
+    /// // Shrinking the width by 6 clamps it to 0, moving the centre.
+    /// let mut rect = Rect::new(0, 4, 4, 5);
+    /// rect.grow_centered(-3, 0);
+    ///
+    /// assert_eq!(rect, Rect::new(3, 4, 0, 5));
+    /// assert_ne!(rect.center(), centre);
+    /// ```
+    pub fn grow_centered(&mut self, dx: i32, dy: i32) {
+        self.expand_each(dx, dy, dx, dy);
+    }
+
+    /// Returns a copy of the rect grown as by [`Rect::grow_centered`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 4, 5);
+    ///
+    /// assert_eq!(rect.grown_centered(2, 0), Rect::new(-2, 4, 8, 5));
+    /// ```
+    pub fn grown_centered(&self, dx: i32, dy: i32) -> Self {
+        let mut rect = *self;
+        rect.grow_centered(dx, dy);
+        rect
+    }
+
+    /// Increases the size of the rectangle as little as possible so that it contains
+    /// the given position. Does nothing if the position is already contained. An
+    /// empty rect becomes a single tile at the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(0, 0, 2, 2);
+    /// rect.grow_to_include(Point::new(4, 2));
+    ///
+    /// assert_eq!(rect, Rect::new(0, 2, 5, 4));
+    /// ```
+    pub fn grow_to_include(&mut self, pos: Point) {
+        if self.is_empty() {
+            *self = Self::new(pos.x, pos.y, 1, 1);
+            return;
+        }
+
+        if pos.x < self.left {
+            self.wid += self.left - pos.x;
+            self.left = pos.x;
+        } else if pos.x > self.right() {
+            self.wid += pos.x - self.right();
+        }
+
+        if pos.y > self.top {
+            self.hgt += pos.y - self.top;
+            self.top = pos.y;
+        } else if pos.y < self.bottom() {
+            self.hgt += self.bottom() - pos.y;
+        }
+    }
+
+    /// Checks whether the given position is within or on the rectangle's boundaries.
+    /// This is correct even for rects too large for [`Rect::right`] or
+    /// [`Rect::bottom`] to fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    ///
+    /// // The above rectangle, below:
+	/// // 'O' is the origin.
+	/// //
+    /// // O-+
+    /// // | |
+    /// // | |
+    /// // | |
+    /// // +-+
+    ///
+    /// assert!(rect.contains(Point::new(1, -3)));
+    /// assert!(rect.contains(Point::new(1, 0)));
+    /// assert!(!rect.contains(Point::new(-1, 0)));
+    /// ```
+    pub const fn contains(&self, pos: Point) -> bool {
+        // Widened so that rects running past the edge of the i32 range do not overflow.
+        let dx = pos.x as i64 - self.left as i64;
+        let dy = self.top as i64 - pos.y as i64;
+
+        dx >= 0 && dx < self.wid as i64 && dy >= 0 && dy < self.hgt as i64
+    }
+
+    /// Checks whether every one of the given positions is within or on the
+    /// rectangle's boundaries. This is true when there are no positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    ///
+    /// assert!(rect.contains_all(&[Point::new(1, -3), Point::new(2, 0)]));
+    /// assert!(!rect.contains_all(&[Point::new(1, -3), Point::new(3, 0)]));
+    /// ```
+    pub fn contains_all(&self, points: &[Point]) -> bool {
+        points.iter().all(|&pos| self.contains(pos))
+    }
+
+    /// Checks whether any of the given positions is within or on the rectangle's
+    /// boundaries. This is false when there are no positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    ///
+    /// assert!(rect.contains_any(&[Point::new(1, -3), Point::new(3, 0)]));
+    /// assert!(!rect.contains_any(&[Point::new(-1, 0), Point::new(3, 0)]));
+    /// ```
+    pub fn contains_any(&self, points: &[Point]) -> bool {
+        points.iter().any(|&pos| self.contains(pos))
+    }
+
+    /// Returns the smallest rect containing every given position that lies within
+    /// the rect, ignoring the others, or None if no position lies within it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let room = Rect::new(0, 5, 6, 6);
+    /// let items = [Point::new(1, 4), Point::new(3, 2), Point::new(9, 9)];
+    ///
+    /// assert_eq!(room.tighten_to(&items), Some(Rect::new(1, 4, 3, 3)));
+    /// assert_eq!(room.tighten_to(&items[2..]), None);
+    /// ```
+    pub fn tighten_to(&self, points: &[Point]) -> Option<Self> {
+        Self::bounding_box(points.iter().copied().filter(|&pos| self.contains(pos)))
+    }
+
+    /// Checks whether the given position is within the rectangle and not on any of
+    /// its edges. These are the positions yielded by [`Rect::inner_cells`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    ///
+    /// assert!(rect.contains_strict(Point::new(1, -3)));
+    /// assert!(!rect.contains_strict(Point::new(1, 0)));
+    /// ```
+    pub const fn contains_strict(&self, pos: Point) -> bool {
+        // Widened for the same reason as in contains.
+        let dx = pos.x as i64 - self.left as i64;
+        let dy = self.top as i64 - pos.y as i64;
+
+        dx > 0 && dx < self.wid as i64 - 1 && dy > 0 && dy < self.hgt as i64 - 1
+    }
+
+    /// Checks whether every tile of other is within or on the rectangle's boundaries.
+    /// A rect always contains itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let outer = Rect::new(0, 5, 6, 6);
+    /// let inner = Rect::new(1, 4, 3, 3);
+    /// let partial = Rect::new(4, 2, 4, 2);
+    ///
+    /// // The above rectangles, below:
+    /// // 'O' is the origin.
+    /// //
+    /// // +----+
+    /// // |+-+ |
+    /// // || | |
+    /// // |+-+ +--+
+    /// // |    |  |
+    /// // O----+--+
+    ///
+    /// assert!(outer.contains_rect(&inner));
+    /// assert!(outer.contains_rect(&outer));
+    /// assert!(!outer.contains_rect(&partial));
+    /// assert!(!inner.contains_rect(&outer));
+    /// ```
+    pub const fn contains_rect(&self, other: &Self) -> bool {
+        self.left <= other.left
+            && self.right() >= other.right()
+            && self.top >= other.top
+            && self.bottom() <= other.bottom()
+    }
+
+    /// Returns the position within or on the rectangle's boundaries nearest to pos.
+    /// Positions already inside the rect are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    ///
+    /// assert_eq!(rect.clamp_point(Point::new(1, -2)), Point::new(1, -2));
+    /// assert_eq!(rect.clamp_point(Point::new(-4, -2)), Point::new(0, -2));
+    /// assert_eq!(rect.clamp_point(Point::new(7, 3)), Point::new(2, 0));
+    /// ```
+    pub fn clamp_point(&self, pos: Point) -> Point {
+        Point::new(
+            pos.x.max(self.left).min(self.right()),
+            pos.y.min(self.top).max(self.bottom()),
+        )
+    }
+
+    /// Returns pos wrapped into the rect as if its opposite edges were joined, so
+    /// moving one tile past the right edge leads to the left edge. Unlike
+    /// [`Rect::clamp_point`], positions any distance outside the rect wrap around as
+    /// many times as needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let world = Rect::new(0, 9, 10, 10);
+    ///
+    /// assert_eq!(world.wrap_point(Point::new(10, 3)), Point::new(0, 3));
+    /// assert_eq!(world.wrap_point(Point::new(-1, 10)), Point::new(9, 0));
+    /// assert_eq!(world.wrap_point(Point::new(4, 5)), Point::new(4, 5));
+    /// ```
+    pub fn wrap_point(&self, pos: Point) -> Point {
+        assert!(!self.is_empty(), "cannot wrap into the empty rect {self}");
+
+        let wrap = |val: i32, start: i32, len: i32| {
+            (start as i64 + (val as i64 - start as i64).rem_euclid(len as i64)) as i32
+        };
+
+        Point::new(wrap(pos.x, self.left, self.wid), wrap(pos.y, self.bottom(), self.hgt))
+    }
+
+    /// Returns the position on the rectangle's edges nearest to pos. Positions
+    /// outside the rect behave as with [`Rect::clamp_point`], while positions inside
+    /// are moved straight onto the nearest edge.
+    ///
+    /// When several edges are equally near, the left edge is preferred, then the
+    /// top, then the right, then the bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 5, 7);
+    ///
+    /// assert_eq!(rect.closest_edge_point(Point::new(3, -2)), Point::new(4, -2));
+    /// assert_eq!(rect.closest_edge_point(Point::new(2, -5)), Point::new(2, -6));
+    /// assert_eq!(rect.closest_edge_point(Point::new(9, -2)), Point::new(4, -2));
+    /// ```
+    pub fn closest_edge_point(&self, pos: Point) -> Point {
+        let clamped = self.clamp_point(pos);
+
+        if clamped != pos {
+            return clamped;
+        }
+
+        let to_left = pos.x - self.left;
+        let to_top = self.top - pos.y;
+        let to_right = self.right() - pos.x;
+        let to_bottom = pos.y - self.bottom();
+        let nearest = to_left.min(to_top).min(to_right).min(to_bottom);
+
+        if nearest == to_left {
+            Point::new(self.left, pos.y)
+        } else if nearest == to_top {
+            Point::new(pos.x, self.top)
+        } else if nearest == to_right {
+            Point::new(self.right(), pos.y)
+        } else {
+            Point::new(pos.x, self.bottom())
+        }
+    }
+
+    /// Returns a uniformly chosen position within or on the rectangle's boundaries,
+    /// or None if the rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 5);
+    /// let pos = rect.random_point(&mut rand::rng()).unwrap();
+    ///
+    /// assert!(rect.contains(pos));
+    /// assert_eq!(Rect::new(0, 0, 0, 5).random_point(&mut rand::rng()), None);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_point<R: rand::Rng>(&self, rng: &mut R) -> Option<Point> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(Point::new(
+            rng.random_range(self.left..=self.right()),
+            rng.random_range(self.bottom()..=self.top),
+        ))
+    }
+
+    /// Returns the bottom left and top right tiles of the rect as glam vectors.
+    /// Both are inclusive, and the y axis is not flipped, so y increases upwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::IVec2;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 5, 3, 4);
+    ///
+    /// assert_eq!(rect.to_glam_min_max(), (IVec2::new(1, 2), IVec2::new(3, 5)));
+    /// ```
+    #[cfg(feature = "glam")]
+    pub fn to_glam_min_max(&self) -> (glam::IVec2, glam::IVec2) {
+        (
+            glam::IVec2::new(self.left, self.bottom()),
+            glam::IVec2::new(self.right(), self.top),
+        )
+    }
+
+    /// Returns the manhattan distance from pos to the nearest tile of the rect,
+    /// or 0 if pos is within or on the rectangle's boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 3);
+    ///
+    /// assert_eq!(rect.manhattan_distance_to(Point::new(1, -1)), 0);
+    /// assert_eq!(rect.manhattan_distance_to(Point::new(5, 2)), 5);
+    /// ```
+    pub fn manhattan_distance_to(&self, pos: Point) -> i32 {
+        let nearest = self.clamp_point(pos);
+
+        (pos.x - nearest.x).abs() + (pos.y - nearest.y).abs()
+    }
+
+    /// Returns the euclidean distance from pos to the nearest tile of the rect,
+    /// or 0 if pos is within or on the rectangle's boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 3, 3);
+    ///
+    /// assert_eq!(rect.distance_to(Point::new(1, -1)), 0.0);
+    /// assert_eq!(rect.distance_to(Point::new(5, 4)), 5.0);
+    /// ```
+    pub fn distance_to(&self, pos: Point) -> f64 {
+        let nearest = self.clamp_point(pos);
+        let dx = (pos.x - nearest.x) as f64;
+        let dy = (pos.y - nearest.y) as f64;
+
+        dx.hypot(dy)
+    }
+
+    /// Returns the euclidean distance between the true centres of the rects, as
+    /// given by [`Rect::center_f64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect1 = Rect::new(0, 1, 2, 2);
+    /// let rect2 = Rect::new(3, 5, 2, 2);
+    ///
+    /// assert_eq!(rect1.center_distance(&rect2), 5.0);
+    /// ```
+    pub fn center_distance(&self, other: &Self) -> f64 {
+        let (x1, y1) = self.center_f64();
+        let (x2, y2) = other.center_f64();
+
+        (x2 - x1).hypot(y2 - y1)
+    }
+
+    /// Returns the manhattan distance between the centre tiles of the rects, as
+    /// given by [`Rect::center`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect1 = Rect::new(0, 1, 2, 2);
+    /// let rect2 = Rect::new(3, 5, 2, 2);
+    ///
+    /// assert_eq!(rect1.center_manhattan_distance(&rect2), 7);
+    /// ```
+    pub fn center_manhattan_distance(&self, other: &Self) -> i32 {
+        let a = self.center();
+        let b = other.center();
+
+        (b.x - a.x).abs() + (b.y - a.y).abs()
+    }
+
+    /// Returns true if the line segment from a to b passes through or touches the
+    /// rect, treating the rect as the region from [`Rect::top_left`] to
+    /// [`Rect::bottom_right`]. Empty rects are never touched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 3);
+    ///
+    /// assert!(rect.intersects_line(Point::new(-3, 0), Point::new(7, 6)));
+    /// assert!(!rect.intersects_line(Point::new(-3, 0), Point::new(7, 0)));
+    /// ```
+    pub fn intersects_line(&self, a: Point, b: Point) -> bool {
+        self.clip_line(a, b).is_some()
+    }
+
+    /// Returns the tiles where the line segment from a to b enters and leaves the
+    /// rect, or None if it misses the rect. Endpoints inside the rect are returned
+    /// unchanged, and other crossing points are rounded to the nearest tile of the
+    /// rect.
+    ///
+    /// This uses the Liang-Barsky algorithm on the rect from [`Rect::top_left`] to
+    /// [`Rect::bottom_right`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 3);
+    ///
+    /// // The above rectangle and the segment, below:
+    /// // 'a' and 'b' are the ends of the segment.
+    /// //
+    /// //    +---+
+    /// // a-------b
+    /// //    +---+
+    ///
+    /// let clipped = rect.clip_line(Point::new(-3, 3), Point::new(5, 3));
+    ///
+    /// assert_eq!(clipped, Some((Point::new(0, 3), Point::new(4, 3))));
+    /// assert_eq!(rect.clip_line(Point::new(1, 3), Point::new(2, 2)), Some((Point::new(1, 3), Point::new(2, 2))));
+    /// ```
+    pub fn clip_line(&self, a: Point, b: Point) -> Option<(Point, Point)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (x, y) = (a.x as f64, a.y as f64);
+        let (dx, dy) = (b.x as f64 - x, b.y as f64 - y);
+        let mut enter = 0.0;
+        let mut exit = 1.0;
+
+        // Each pair is how fast the segment moves out through one edge, and how far
+        // inside that edge it starts.
+        let edges = [
+            (-dx, x - self.left as f64),
+            (dx, self.right() as f64 - x),
+            (-dy, y - self.bottom() as f64),
+            (dy, self.top as f64 - y),
+        ];
+
+        for (rate, dist) in edges {
+            if rate == 0.0 {
+                if dist < 0.0 {
+                    return None;
+                }
+            } else {
+                let t = dist / rate;
+
+                if rate < 0.0 {
+                    enter = t.max(enter);
+                } else {
+                    exit = t.min(exit);
+                }
+            }
+        }
+
+        if enter > exit {
+            return None;
+        }
+
+        let at = |t: f64| {
+            self.clamp_point(Point::new((x + dx * t).round() as i32, (y + dy * t).round() as i32))
+        };
+
+        Some((at(enter), at(exit)))
+    }
+
+    /// Return an iterator over all positions contained
+    /// within the rect, including the edges.
+    #[inline]
+    pub fn cells(&self) -> InteriorIter {
+        InteriorIter::from(*self)
+    }
+
+    /// Return all positions contained within the rect, excluding the edges.
+    /// Rects less than 3 tiles wide or tall have no interior, so yield nothing.
+    pub fn inner_cells(&self) -> InteriorIter {
+        if self.wid < 3 || self.hgt < 3 {
+            return InteriorIter::from(Rect::new(self.left, self.top, 0, 0));
+        }
+
+        InteriorIter::from(Rect::new(self.left + 1, self.top - 1, self.wid - 2, self.hgt - 2))
+    }
+
+    /// Return an iterator over the positions contained within both the rect and
+    /// bounds, including the edges. Yields nothing if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let world = Rect::new(0, 9, 10, 10);
+    /// let view = Rect::new(8, 1, 4, 4);
+    ///
+    /// assert_eq!(world.clip(&view).count(), 4);
+    /// assert!(world.clip(&view).all(|pos| view.contains(pos)));
+    /// assert_eq!(world.clip(&Rect::new(20, 1, 4, 4)).next(), None);
+    /// ```
+    pub fn clip(&self, bounds: &Self) -> InteriorIter {
+        self.intersection(bounds)
+            .unwrap_or(Rect::new(self.left, self.top, 0, 0))
+            .cells()
+    }
+
+    /// Return an iterator over the positions along row y of the rect, from left to
+    /// right. Yields nothing if y is outside the rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 3, 3, 4);
+    ///
+    /// assert_eq!(rect.row_cells(2).collect::<Vec<_>>(), vec![Point::new(1, 2), Point::new(2, 2), Point::new(3, 2)]);
+    /// assert_eq!(rect.row_cells(4).next(), None);
+    /// ```
+    pub fn row_cells(&self, y: i32) -> InteriorIter {
+        self.clip(&Rect::new(self.left, y, self.wid, 1))
+    }
+
+    /// Return an iterator over the positions along column x of the rect, from top
+    /// to bottom. Yields nothing if x is outside the rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 3, 3, 2);
+    ///
+    /// assert_eq!(rect.column_cells(3).collect::<Vec<_>>(), vec![Point::new(3, 3), Point::new(3, 2)]);
+    /// assert_eq!(rect.column_cells(0).next(), None);
+    /// ```
+    pub fn column_cells(&self, x: i32) -> InteriorIter {
+        self.clip(&Rect::new(x, self.top, 1, self.hgt))
+    }
+
+    /// Returns the tiles along one side of the rect, including the corners at each
+    /// end. The top and bottom sides run left to right, and the left and right sides
+    /// run top to bottom. Empty rects have no tiles on any side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::{Rect, Side};
+    ///
+    /// let rect = Rect::new(0, 2, 4, 3);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// //
+    /// // +--+
+    /// // |  |
+    /// // O--+
+    ///
+    /// assert_eq!(rect.side_tiles(Side::Right), vec![Point::new(3, 2), Point::new(3, 1), Point::new(3, 0)]);
+    /// assert_eq!(rect.side_tiles(Side::Top).len(), 4);
+    /// ```
+    pub fn side_tiles(&self, side: Side) -> Vec<Point> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        match side {
+            Side::Top => self.row_cells(self.top).collect(),
+            Side::Bottom => self.row_cells(self.bottom()).collect(),
+            Side::Left => self.column_cells(self.left).collect(),
+            Side::Right => self.column_cells(self.right()).collect(),
+        }
+    }
+
+    /// Return an iterator over all positions contained within the rect, including
+    /// the edges, spiralling outwards from [`Rect::center`]. Positions come in rings
+    /// of increasing chebyshev distance from the centre, so the centre is first and
+    /// the edges of a square rect are last. Each ring is walked clockwise from its
+    /// top left corner, as with [`Rect::edges`], skipping any part of it outside the
+    /// rect.
+    ///
+    /// Positions are found as they are yielded, so a large rect is never held in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 3, 3);
+    /// let spiral = rect.cells_spiral().collect::<Vec<_>>();
+    ///
+    /// // The above rectangle, below:
+    /// // Each tile is numbered by when it is yielded.
+    /// //
+    /// // 123
+    /// // 804
+    /// // 765
+    ///
+    /// assert_eq!(spiral[0], Point::new(1, 1));
+    /// assert_eq!(spiral[1..4], [Point::new(0, 2), Point::new(1, 2), Point::new(2, 2)]);
+    /// assert_eq!(spiral[4..], [
+    ///     Point::new(2, 1),
+    ///     Point::new(2, 0),
+    ///     Point::new(1, 0),
+    ///     Point::new(0, 0),
+    ///     Point::new(0, 1),
+    /// ]);
+    /// ```
+    pub fn cells_spiral(&self) -> impl Iterator<Item = Point> + use<> {
+        let rect = *self;
+        let centre = self.center();
+        let rings = if self.is_empty() {
+            0
+        } else {
+            (centre.x - self.left)
+                .max(self.right() - centre.x)
+                .max(self.top - centre.y)
+                .max(centre.y - self.bottom())
+                + 1
+        };
+
+        (0..rings).flat_map(move |ring| rect.spiral_ring(centre, ring))
+    }
+
+    /// Returns the tiles of the rect exactly ring tiles from centre by chebyshev
+    /// distance, clockwise from the top left corner of the ring, for
+    /// [`Rect::cells_spiral`].
+    fn spiral_ring(&self, centre: Point, ring: i32) -> impl Iterator<Item = Point> + use<> {
+        // Widened, as a ring can reach past the edge of the i32 range.
+        let (x, y, ring) = (centre.x as i64, centre.y as i64, ring as i64);
+        let (left, right) = (self.left as i64, self.right() as i64);
+        let (top, bottom) = (self.top as i64, self.bottom() as i64);
+
+        // Each side of the ring is kept only if it lies within the rect, and is clipped
+        // to the rect. A ring of 0 is just the centre, which the top side covers.
+        let keep = |inside: bool| if inside { usize::MAX } else { 0 };
+        let side = |inside: bool| keep(inside && ring > 0);
+        let point = |x: i64, y: i64| Point::new(x as i32, y as i32);
+
+        let top_side = ((x - ring).max(left)..=(x + ring).min(right))
+            .take(keep(y + ring <= top))
+            .map(move |x| point(x, y + ring));
+        let right_side = ((y - ring).max(bottom)..=(y + ring - 1).min(top))
+            .rev()
+            .take(side(x + ring <= right))
+            .map(move |y| point(x + ring, y));
+        let bottom_side = ((x - ring).max(left)..=(x + ring - 1).min(right))
+            .rev()
+            .take(side(y - ring >= bottom))
+            .map(move |x| point(x, y - ring));
+        let left_side = ((y - ring + 1).max(bottom)..=(y + ring - 1).min(top))
+            .take(side(x - ring >= left))
+            .map(move |y| point(x - ring, y));
+
+        top_side.chain(right_side).chain(bottom_side).chain(left_side)
+    }
+
+    /// Return an iterator over all positions contained within the rect, in the same
+    /// order as [`Rect::cells`], each paired with its `(column, row)` within the
+    /// rect. The top left tile is `(0, 0)`, and rows count downwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(3, 5, 2, 2);
+    /// let cells = rect.cells_indexed().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(cells[0], ((0, 0), Point::new(3, 5)));
+    /// assert_eq!(cells[2], ((0, 1), Point::new(3, 4)));
+    /// ```
+    pub fn cells_indexed(&self) -> impl Iterator<Item = ((u32, u32), Point)> + use<> {
+        let (left, top) = (self.left, self.top);
+
+        self.cells().map(move |pos| (((pos.x - left) as u32, (top - pos.y) as u32), pos))
+    }
+
+    /// Returns the index of pos among the positions of the rect, counting row by row
+    /// from the top left as [`Rect::cells`] does, or None if pos is not in the rect.
+    /// This is the inverse of [`Rect::point_at_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(3, 5, 4, 2);
+    ///
+    /// assert_eq!(rect.index_of(Point::new(3, 5)), Some(0));
+    /// assert_eq!(rect.index_of(Point::new(4, 4)), Some(5));
+    /// assert_eq!(rect.index_of(Point::new(4, 3)), None);
+    /// ```
+    pub fn index_of(&self, pos: Point) -> Option<usize> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        let col = (pos.x as i64 - self.left as i64) as usize;
+        let row = (self.top as i64 - pos.y as i64) as usize;
+
+        Some(row * self.wid as usize + col)
+    }
+
+    /// Returns the position at the given index of the rect, counting row by row from
+    /// the top left as [`Rect::cells`] does, or None if the index is not less than
+    /// the number of tiles in the rect. This is the inverse of [`Rect::index_of`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(3, 5, 4, 2);
+    ///
+    /// assert_eq!(rect.point_at_index(5), Some(Point::new(4, 4)));
+    /// assert_eq!(rect.point_at_index(8), None);
+    /// ```
+    pub fn point_at_index(&self, idx: usize) -> Option<Point> {
+        if self.is_empty() || idx as u64 >= self.checked_area()? {
+            return None;
+        }
+
+        let wid = self.wid as usize;
+        let col = (idx % wid) as i32;
+        let row = (idx / wid) as i32;
+
+        Some(Point::new(self.left + col, self.top - row))
+    }
+
+    /// Splits the positions of the rect into those where `x + y` is even and those
+    /// where it is odd, like the two colours of a checkerboard. Each list keeps the
+    /// order of [`Rect::cells`], and together they hold every tile exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 1, 2, 2);
+    /// let (even, odd) = rect.checkerboard();
+    ///
+    /// assert_eq!(even, vec![Point::new(1, 1), Point::new(0, 0)]);
+    /// assert_eq!(odd, vec![Point::new(0, 1), Point::new(1, 0)]);
+    /// ```
+    pub fn checkerboard(&self) -> (Vec<Point>, Vec<Point>) {
+        self.cells().partition(|pos| (pos.x as i64 + pos.y as i64) % 2 == 0)
+    }
+
+    /// Returns the area of the rectangle.
+    ///
+    /// The product is computed without overflowing, but areas too large to fit in
+    /// a `u32` saturate at `u32::MAX`, and negative dimensions count by their
+    /// length. Use [`Rect::checked_area`] to get the exact area instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 5, 3, 5);
+    ///
+    /// // The above rectangle, below:
+	/// // 'O' is the origin.
+	/// //
+    /// // +-+
+    /// // | |
+    /// // | |
+    /// // | |
+    /// // O-+
+    ///
+    /// assert_eq!(rect.area(), 15);
+    /// ```
+    pub const fn area(&self) -> u32 {
+        let area = self.exact_area();
+
+        if area > u32::MAX as u64 {
+            u32::MAX
+        } else {
+            area as u32
+        }
+    }
+
+    /// The area without saturating, which always fits in a `u64`.
+    const fn exact_area(&self) -> u64 {
+        self.wid.unsigned_abs() as u64 * self.hgt.unsigned_abs() as u64
+    }
+
+    /// Returns the exact area of the rectangle, or None if the width or height is
+    /// negative. Every area of a rect with `i32` dimensions fits in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::new(0, 5, 3, 5).checked_area(), Some(15));
+    /// assert_eq!(Rect::new(0, 0, i32::MAX, 4).checked_area(), Some(i32::MAX as u64 * 4));
+    /// assert_eq!(Rect::new(0, 0, -3, 5).checked_area(), None);
+    /// ```
+    pub const fn checked_area(&self) -> Option<u64> {
+        if self.wid < 0 || self.hgt < 0 {
+            return None;
+        }
+
+        Some(self.wid as u64 * self.hgt as u64)
+    }
+
+    /// Compares two rects by area. Unlike comparing [`Rect::area`], areas too large
+    /// for a `u32` are still told apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let mut rects = vec![Rect::new(0, 0, 2, 2), Rect::new(0, 0, 5, 1), Rect::new(0, 0, 1, 1)];
+    ///
+    /// // Largest first.
+    /// rects.sort_by(|a, b| Rect::cmp_area(b, a));
+    ///
+    /// assert_eq!(rects[0], Rect::new(0, 0, 5, 1));
+    /// assert_eq!(rects[2], Rect::new(0, 0, 1, 1));
+    /// ```
+    pub fn cmp_area(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.exact_area().cmp(&b.exact_area())
+    }
+
+    /// Returns true if the rect has no width or no height. An empty rect contains
+    /// no positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 0, 3);
+    ///
+    /// assert!(rect.is_empty());
+    /// assert!(!rect.contains(Point::new(0, 0)));
+    /// assert!(!Rect::new(0, 0, 1, 1).is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.wid <= 0 || self.hgt <= 0
+    }
+
+    /// Returns true if the rect has a positive width and height.
+    /// This is the opposite of [`Rect::is_empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert!(Rect::new(0, 0, 2, 3).is_valid());
+    /// assert!(!Rect::new(0, 0, -2, 3).is_valid());
+    /// ```
+    pub const fn is_valid(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns an equivalent rect with non-negative width and height.
+    ///
+    /// A negative width is taken to extend left from the left edge, so the rect
+    /// covers the `-wid` columns immediately left of `left`. Likewise a negative
+    /// height covers the `-hgt` rows immediately above `top`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(5, 0, -3, 2);
+    ///
+    /// assert_eq!(rect.normalized(), Rect::new(2, 0, 3, 2));
+    /// assert_eq!(Rect::new(2, 0, 3, 2).normalized(), Rect::new(2, 0, 3, 2));
+    /// ```
+    pub fn normalized(&self) -> Self {
+        let mut rect = *self;
+
+        if rect.wid < 0 {
+            rect.left += rect.wid;
+            rect.wid = -rect.wid;
+        }
+
+        if rect.hgt < 0 {
+            rect.top -= rect.hgt;
+            rect.hgt = -rect.hgt;
+        }
+
+        rect
+    }
+
+    /// Returns the number of tiles on the edges of the rectangle.
+    /// This is the number of points yielded by [`Rect::edges`].
+    ///
+    /// Like [`Rect::area`], this is computed without overflowing, but perimeters
+    /// too large to fit in a `u32` saturate at `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 5, 3, 5);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// //
+    /// // +-+
+    /// // | |
+    /// // | |
+    /// // | |
+    /// // O-+
+    ///
+    /// assert_eq!(rect.perimeter(), 12);
+    /// assert_eq!(rect.edges().count(), 12);
+    /// ```
+    pub const fn perimeter(&self) -> u32 {
+        let perimeter = self.exact_perimeter();
+
+        if perimeter > u32::MAX as u64 {
+            u32::MAX
+        } else {
+            perimeter as u32
+        }
+    }
+
+    /// The perimeter without saturating, which always fits in a `u64`.
+    const fn exact_perimeter(&self) -> u64 {
+        if self.wid <= 0 || self.hgt <= 0 {
+            0
+        } else if self.wid == 1 || self.hgt == 1 {
+            self.wid as u64 * self.hgt as u64
+        } else {
+            2 * self.wid as u64 + 2 * self.hgt as u64 - 4
+        }
+    }
+
+    /// Returns the width of the rect divided by its height. A rect with a positive
+    /// width but no height has an aspect ratio of `f64::INFINITY`, rather than panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::new(0, 0, 4, 2).aspect_ratio(), 2.0);
+    /// assert_eq!(Rect::new(0, 0, 4, 0).aspect_ratio(), f64::INFINITY);
+    /// ```
+    pub fn aspect_ratio(&self) -> f64 {
+        self.wid as f64 / self.hgt as f64
+    }
+
+    /// Returns true if the width and height of the rect are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert!(Rect::new(0, 0, 3, 3).is_square());
+    /// assert!(!Rect::new(0, 0, 3, 2).is_square());
+    /// ```
+    pub const fn is_square(&self) -> bool {
+        self.wid == self.hgt
+    }
+
+    /// Returns true if both rects have the same width and height, wherever they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert!(Rect::new(0, 0, 3, 2).same_size(&Rect::new(-5, 9, 3, 2)));
+    /// assert!(!Rect::new(0, 0, 3, 2).same_size(&Rect::new(0, 0, 2, 3)));
+    /// ```
+    pub const fn same_size(&self, other: &Self) -> bool {
+        self.wid == other.wid && self.hgt == other.hgt
+    }
+
+    /// Returns true if both rects have the same size once [`Rect::normalized`], so
+    /// a negative width or height matches the positive one of the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert!(Rect::new(0, 0, -3, 2).same_shape_normalized(&Rect::new(4, 1, 3, 2)));
+    /// assert!(!Rect::new(0, 0, -3, 2).same_size(&Rect::new(4, 1, 3, 2)));
+    /// ```
+    pub fn same_shape_normalized(&self, other: &Self) -> bool {
+        self.normalized().same_size(&other.normalized())
+    }
+
+    /// Returns true if both rects cover the same tiles once [`Rect::normalized`],
+    /// even if one stores a negative width or height and the other does not. Rects
+    /// covering no tiles at all always match each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 3, 2);
+    /// let flipped = Rect::new(3, 0, -3, -2);
+    ///
+    /// assert_ne!(rect, flipped);
+    /// assert!(rect.covers_same_tiles(&flipped));
+    /// ```
+    pub fn covers_same_tiles(&self, other: &Self) -> bool {
+        let rect = self.normalized();
+        let other = other.normalized();
+
+        (rect.is_empty() && other.is_empty()) || rect == other
+    }
+
+    /// Returns the length of the rect's diagonal. Like [`Rect::area`], this measures
+    /// the width and height in tiles, so it runs from the outer corner of the top left
+    /// tile to the outer corner of the bottom right tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::new(0, 0, 1, 1).diagonal_length(), 2.0_f64.sqrt());
+    /// ```
+    pub fn diagonal_length(&self) -> f64 {
+        (self.wid as f64).hypot(self.hgt as f64)
+    }
+
+    /// Relocates the rect's top left corner to the given position.
+    pub fn move_to(&mut self, pos: Point) {
+        self.left = pos.x;
+        self.top = pos.y;
+    }
+
+    /// Moves the rect by the given offset, leaving its size unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(1, 1, 3, 2);
+    /// rect.move_by(Point::new(2, -1));
+    ///
+    /// assert_eq!(rect, Rect::new(3, 0, 3, 2));
+    /// ```
+    pub fn move_by(&mut self, delta: Point) {
+        self.left += delta.x;
+        self.top += delta.y;
+    }
+
+    /// Returns a copy of the rect moved by the given offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 3, 2);
+    ///
+    /// assert_eq!(rect.translated(Point::new(-1, 4)), Rect::new(0, 5, 3, 2));
+    /// assert_eq!(rect, Rect::new(1, 1, 3, 2));
+    /// ```
+    pub fn translated(&self, delta: Point) -> Self {
+        let mut rect = *self;
+        rect.move_by(delta);
+        rect
+    }
+
+    /// Returns a copy of the rect with f applied to its top left corner, keeping
+    /// its width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 5, 3, 2);
+    ///
+    /// assert_eq!(rect.map_position(|pos| Point::new(pos.x, -pos.y)), Rect::new(1, -5, 3, 2));
+    /// ```
+    pub fn map_position(&self, f: impl Fn(Point) -> Point) -> Self {
+        self.with_position(f(self.top_left()))
+    }
+
+    /// Returns the smallest rect containing every corner of the rect after f is
+    /// applied to it. Unlike [`Rect::map_position`], this handles transforms that
+    /// flip or rotate the rect, such as negating the y co-ords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 5, 3, 2);
+    /// let flipped = rect.map_corners(|pos| Point::new(pos.x, -pos.y));
+    ///
+    /// assert_eq!(flipped, Rect::new(1, -4, 3, 2));
+    /// assert!(rect.cells().all(|pos| flipped.contains(Point::new(pos.x, -pos.y))));
+    /// ```
+    pub fn map_corners(&self, f: impl Fn(Point) -> Point) -> Self {
+        let [top_left, top_right, bottom_left, bottom_right] = self.corners().map(f);
+        let mut rect = Self::from_corners(top_left, bottom_right);
+        rect.grow_to_include(top_right);
+        rect.grow_to_include(bottom_left);
+        rect
+    }
+
+    /// Returns the rect a fraction t of the way from this rect to other, found by
+    /// interpolating `left`, `top`, `wid` and `hgt` separately. Each field is
+    /// rounded to the nearest tile, with halves rounded away from zero, so a t of
+    /// 0 gives this rect and a t of 1 gives other exactly. Values of t outside 0 to
+    /// 1 carry on past either rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let from = Rect::new(0, 0, 2, 2);
+    /// let to = Rect::new(10, 5, 6, 3);
+    ///
+    /// assert_eq!(from.lerp(&to, 0.5), Rect::new(5, 3, 4, 3));
+    /// assert_eq!(from.lerp(&to, 1.0), to);
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: i32, b: i32| (a as f64 + (b as f64 - a as f64) * t).round() as i32;
+
+        Self::new(
+            lerp(self.left, other.left),
+            lerp(self.top, other.top),
+            lerp(self.wid, other.wid),
+            lerp(self.hgt, other.hgt),
+        )
+    }
+
+    /// Returns a copy of the rect with the given width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(3, 3, 2, 2).with_width(10).with_position(Point::new(0, 0));
+    ///
+    /// assert_eq!(rect, Rect::new(0, 0, 10, 2));
+    /// ```
+    pub const fn with_width(self, wid: i32) -> Self {
+        Self { wid, ..self }
+    }
+
+    /// Returns a copy of the rect with the given height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::new(3, 3, 2, 2).with_height(5), Rect::new(3, 3, 2, 5));
+    /// ```
+    pub const fn with_height(self, hgt: i32) -> Self {
+        Self { hgt, ..self }
+    }
+
+    /// Returns a copy of the rect with its top left corner at the given position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// assert_eq!(Rect::new(3, 3, 2, 2).with_position(Point::new(-1, 4)), Rect::new(-1, 4, 2, 2));
+    /// ```
+    pub fn with_position(self, pos: Point) -> Self {
+        let mut rect = self;
+        rect.move_to(pos);
+        rect
+    }
+
+    /// Returns a copy of the rect with its width and height multiplied by factor,
+    /// keeping the top left corner in place.
+    ///
+    /// A factor of 0 gives an empty rect, and a negative factor gives a rect with
+    /// negative dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 3, 2);
+    ///
+    /// assert_eq!(rect.scale(2), Rect::new(1, 1, 6, 4));
+    /// assert_eq!(rect.scale(0).area(), 0);
+    /// ```
+    pub fn scale(&self, factor: i32) -> Self {
+        self.scale_from(factor, self.top_left())
+    }
+
+    /// Returns a copy of the rect scaled by factor about the given anchor, so the
+    /// distance from the anchor to the top left corner is multiplied along with the
+    /// width and height. Factors of 0 and below behave as in [`Rect::scale`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 3, 2);
+    ///
+    /// assert_eq!(rect.scale_from(2, Point::new(0, 0)), Rect::new(2, 2, 6, 4));
+    /// ```
+    pub fn scale_from(&self, factor: i32, anchor: Point) -> Self {
+        Self::new(
+            anchor.x + (self.left - anchor.x) * factor,
+            anchor.y + (self.top - anchor.y) * factor,
+            self.wid * factor,
+            self.hgt * factor,
+        )
+    }
+
+    /// Returns a copy of the rect with its width and height multiplied by factor,
+    /// keeping [`Rect::center`] in place. The new width and height are rounded to
+    /// the nearest tile, with halves rounded away from zero.
+    ///
+    /// A factor below 1 shrinks the rect, and a factor of 0 or below gives an empty
+    /// rect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    ///
+    /// assert_eq!(rect.scale_f64(1.5), Rect::new(-2, 6, 8, 8));
+    /// assert_eq!(rect.scale_f64(1.5).center(), rect.center());
+    /// ```
+    pub fn scale_f64(&self, factor: f64) -> Self {
+        let wid = (self.wid as f64 * factor).round().max(0.0) as i32;
+        let hgt = (self.hgt as f64 * factor).round().max(0.0) as i32;
+        let mut rect = Self::new(0, 0, wid, hgt);
+        rect.centre_on(self.center());
+        rect
+    }
+
+    /// Returns the smallest rect made of whole grid cells that covers the rect,
+    /// where each grid cell is grid tiles wide and tall and the tile at the origin
+    /// is the bottom left of a cell.
+    ///
+    /// `left` and [`Rect::bottom`] are rounded down to a multiple of grid, while
+    /// [`Rect::right`] and `top` are rounded up to one less than a multiple of grid,
+    /// so `wid` and `hgt` become multiples of grid. Rects already made of whole grid
+    /// cells are returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if grid is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(3, 9, 6, 5);
+    ///
+    /// assert_eq!(rect.snap_to_grid(4), Rect::new(0, 11, 12, 8));
+    /// assert_eq!(Rect::new(0, 11, 12, 8).snap_to_grid(4), Rect::new(0, 11, 12, 8));
+    /// ```
+    pub fn snap_to_grid(&self, grid: i32) -> Self {
+        assert!(grid > 0, "grid size must be positive, got {grid}");
+
+        let left = self.left.div_euclid(grid) * grid;
+        let bottom = self.bottom().div_euclid(grid) * grid;
+        let right = (self.right().div_euclid(grid) + 1) * grid - 1;
+        let top = (self.top.div_euclid(grid) + 1) * grid - 1;
+
+        Self::new(left, top, right - left + 1, top - bottom + 1)
+    }
+
+    /// Returns a copy of the rect with its width and height each increased to the
+    /// next multiple of m, keeping the top left corner in place. Dimensions that are
+    /// already a multiple of m, including 0, are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if m is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 5, 8);
+    ///
+    /// assert_eq!(rect.grow_dims_to_multiple(4), Rect::new(1, 1, 8, 8));
+    /// ```
+    pub fn grow_dims_to_multiple(&self, m: i32) -> Self {
+        assert!(m > 0, "multiple must be positive, got {m}");
+
+        let grow = |length: i32| length + (m - length.rem_euclid(m)) % m;
+
+        Self::new(self.left, self.top, grow(self.wid), grow(self.hgt))
+    }
+
+    /// Returns a copy of the rect with its width and height each increased to the
+    /// next power of two, keeping the top left corner in place. Dimensions that are
+    /// already a power of two are unchanged, as are dimensions of 0 or less.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension is larger than `2^30`, as the next power of two would
+    /// not fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 5, 8);
+    ///
+    /// assert_eq!(rect.grow_dims_to_pow2(), Rect::new(1, 1, 8, 8));
+    /// ```
+    pub fn grow_dims_to_pow2(&self) -> Self {
+        let grow = |length: i32| {
+            if length <= 0 {
+                return length;
+            }
+
+            i32::try_from((length as u32).next_power_of_two())
+                .expect("dimension too large to grow to a power of two")
+        };
+
+        Self::new(self.left, self.top, grow(self.wid), grow(self.hgt))
+    }
+
+    /// Centres the rect on the given position. When it is not possible to centre
+	/// exactly on the provided co-ordinates, the new centre will be to the right of
+	/// and/or below the true centre.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(0, 4, 4, 4);
+	/// rect.centre_on(Point::new(4, 4));
+    ///
+    /// // The above rectangle (before centring), below:
+	/// // 'C' is where the centre would be placed.
+	/// //     C
+    /// // +--+
+    /// // |  |
+    /// // |  |
+    /// // O--+
+    /// //
+	/// // After centring:
+	/// //              
+	/// //   +--+          
+	/// //   |  |          
+	/// //   | C|        
+	/// //   +--+      
+	/// //         
+	/// //        
+	/// // O   
+	/// //
+	/// 
+    /// assert_eq!(rect.top_left(), Point::new(2, 6));
+    /// ```
+    pub fn centre_on(&mut self, centre: Point) {
+        self.move_by(self.centering_offset(centre));
+    }
+
+    /// Returns how far [`Rect::centre_on`] would move the rect to centre it on the
+    /// given position. Adding it to the top left corner gives the new top left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(0, 4, 4, 4);
+    /// let offset = rect.centering_offset(Point::new(4, 4));
+    ///
+    /// assert_eq!(offset, Point::new(2, 2));
+    ///
+    /// rect.centre_on(Point::new(4, 4));
+    ///
+    /// assert_eq!(rect.top_left(), Point::new(2, 6));
+    /// ```
+    pub fn centering_offset(&self, centre: Point) -> Point {
+        Point::new(centre.x - self.wid / 2 - self.left, centre.y + self.hgt / 2 - self.top)
+    }
+
+    /// Moves the rect so that its true centre, as given by [`Rect::center_f64`], is
+    /// as close as possible to the given position, rounding to the nearest tile.
+    /// Rects with odd dimensions are placed exactly as [`Rect::centre_on`] places them.
+    ///
+    /// An even width or height leaves the true centre half a tile from the position
+    /// either way. As with [`f64::round`], the tie is broken away from the origin, or
+    /// right and up for a position at 0, so centring on `-centre` mirrors centring on
+    /// `centre`. [`Rect::centre_on`] always puts it to the right and below instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let mut rect = Rect::new(0, 4, 4, 4);
+    /// rect.centre_on_round(Point::new(4, 4));
+    ///
+    /// // After centring:
+    /// // 'C' is the given centre.
+    /// //
+    /// //    +--+
+    /// //    |  |
+    /// //    |C |
+    /// //    +--+
+    ///
+    /// assert_eq!(rect.top_left(), Point::new(3, 6));
+    /// assert_eq!(rect.center_f64(), (4.5, 4.5));
+    ///
+    /// rect.centre_on_round(Point::new(-4, -4));
+    ///
+    /// assert_eq!(rect.top_left(), Point::new(-6, -3));
+    /// assert_eq!(rect.center_f64(), (-4.5, -4.5));
+    /// ```
+    pub fn centre_on_round(&mut self, centre: Point) {
+        // Doubled co-ords of where the true centre goes, half a tile from centre when
+        // the length is even.
+        let round = |pos: i32, length: i32| {
+            let tie = if length % 2 != 0 { 0 } else if pos < 0 { -1 } else { 1 };
+            2 * pos as i64 + tie
+        };
+        let left = (round(centre.x, self.wid) - (self.wid as i64 - 1)) / 2;
+        let top = (round(centre.y, self.hgt) + (self.hgt as i64 - 1)) / 2;
+
+        self.move_to(Point::new(left as i32, top as i32));
+    }
+
+    /// Returns the centre of the rect. When the rect has no exact centre tile, the
+    /// centre is taken to the right of and/or below the true centre, matching
+    /// [`Rect::centre_on`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let odd = Rect::new(0, 2, 3, 3);
+    /// let even = Rect::new(0, 4, 4, 4);
+    ///
+    /// // The above rectangles, below:
+    /// // 'C' is the centre of each.
+    /// //
+    /// // +-+     +--+
+    /// // |C|     |  |
+    /// // +-+     | C|
+    /// //         +--+
+    ///
+    /// assert_eq!(odd.center(), Point::new(1, 1));
+    /// assert_eq!(even.center(), Point::new(2, 2));
+    /// ```
+    pub fn center(&self) -> Point {
+        Point::new(self.left + self.wid / 2, self.top - self.hgt / 2)
+    }
+
+    /// Returns the true centre of the rect as x and y co-ords, without rounding to a
+    /// tile. Co-ords name tiles, so the centre of a rect with an even width lies
+    /// halfway between its two middle columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let odd = Rect::new(0, 2, 3, 3);
+    /// let even = Rect::new(0, 4, 4, 4);
+    ///
+    /// assert_eq!(odd.center_f64(), (1.0, 1.0));
+    /// assert_eq!(even.center_f64(), (1.5, 2.5));
+    /// ```
+    pub fn center_f64(&self) -> (f64, f64) {
+        (
+            self.left as f64 + (self.wid as f64 - 1.0) / 2.0,
+            self.top as f64 - (self.hgt as f64 - 1.0) / 2.0,
+        )
+    }
+
+    /// Returns pos reflected through the true centre of the rect, as given by
+    /// [`Rect::center_f64`], so each corner maps to the opposite corner. No rounding
+    /// is needed, but for a rect with an even width or height there is no tile at
+    /// the true centre, so [`Rect::center`] maps to a neighbouring tile rather than
+    /// itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 5);
+    ///
+    /// assert_eq!(rect.mirror_point(Point::new(1, 4)), Point::new(3, 0));
+    /// assert_eq!(rect.mirror_point(rect.center()), rect.center());
+    /// ```
+    pub fn mirror_point(&self, pos: Point) -> Point {
+        let x = self.left as i64 + self.right() as i64 - pos.x as i64;
+        let y = self.top as i64 + self.bottom() as i64 - pos.y as i64;
+
+        Point::new(x as i32, y as i32)
+    }
+
+    /// Returns a copy of the rect moved, but not resized, to the given alignment
+    /// within the container. Centring rounds the same way as [`Rect::centre_on`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::{Alignment, Rect};
+    ///
+    /// let container = Rect::new(0, 5, 7, 6);
+    /// let rect = Rect::new(10, 10, 3, 2);
+    ///
+    /// // The container, with the rect aligned to the centre and bottom right:
+    /// // 'O' is the origin.
+    /// //
+    /// // +-----+
+    /// // |     |
+    /// // | +-+ |
+    /// // | +-+ |
+    /// // |   +-+
+    /// // O---+-+
+    ///
+    /// assert_eq!(rect.align_to(&container, Alignment::Center), Rect::new(2, 3, 3, 2));
+    /// assert_eq!(rect.align_to(&container, Alignment::BottomRight), Rect::new(4, 1, 3, 2));
+    /// ```
+    pub fn align_to(&self, container: &Self, align: Alignment) -> Self {
+        let left = match align {
+            Alignment::TopLeft | Alignment::Left | Alignment::BottomLeft => container.left,
+            Alignment::Top | Alignment::Center | Alignment::Bottom => {
+                container.left + container.wid / 2 - self.wid / 2
+            }
+            Alignment::TopRight | Alignment::Right | Alignment::BottomRight => {
+                container.left + container.wid - self.wid
+            }
+        };
+        let top = match align {
+            Alignment::TopLeft | Alignment::Top | Alignment::TopRight => container.top,
+            Alignment::Left | Alignment::Center | Alignment::Right => {
+                container.top - container.hgt / 2 + self.hgt / 2
+            }
+            Alignment::BottomLeft | Alignment::Bottom | Alignment::BottomRight => {
+                container.top - container.hgt + self.hgt
+            }
+        };
+
+        Self::new(left, top, self.wid, self.hgt)
+    }
+
+    /// Changes the width and height of the rect, moving it so that the tile at the
+    /// given anchor stays in place. An [`Alignment::Center`] anchor keeps
+    /// [`Rect::center`] fixed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::{Alignment, Rect};
+    ///
+    /// let mut rect = Rect::new(0, 4, 3, 3);
+    /// rect.resize(5, 2, Alignment::BottomRight);
+    ///
+    /// assert_eq!(rect, Rect::new(-2, 3, 5, 2));
+    ///
+    /// let mut rect = Rect::new(0, 4, 3, 3);
+    /// rect.resize(1, 5, Alignment::TopLeft);
+    ///
+    /// assert_eq!(rect, Rect::new(0, 4, 1, 5));
+    /// ```
+    pub fn resize(&mut self, new_wid: i32, new_hgt: i32, anchor: Alignment) {
+        *self = Self::new(self.left, self.top, new_wid, new_hgt).align_to(self, anchor);
+    }
+
+    /// Returns a copy of the rect shrunk to the largest size that fits inside the
+    /// container while keeping its aspect ratio, centred in the container as by
+    /// [`Rect::align_to`]. Rects that already fit keep their size and are only
+    /// moved. This never makes a rect larger.
+    ///
+    /// The side that limits the size shrinks to exactly fit the container, and the
+    /// other side is rounded to the nearest tile, halves rounding up, but never to
+    /// less than one tile. If either rect is empty, an empty rect is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let screen = Rect::new(0, 8, 16, 9);
+    /// let video = Rect::new(0, 0, 8, 3);
+    ///
+    /// assert_eq!(video.fit_within(&screen), Rect::new(4, 5, 8, 3));
+    /// assert_eq!(Rect::new(0, 0, 32, 12).fit_within(&screen), Rect::new(0, 7, 16, 6));
+    /// ```
+    pub fn fit_within(&self, container: &Self) -> Self {
+        if self.is_empty() || container.is_empty() {
+            return Self::new(0, 0, 0, 0).align_to(container, Alignment::Center);
+        }
+
+        let (wid, hgt) = (self.wid as i64, self.hgt as i64);
+        let (max_wid, max_hgt) = (container.wid as i64, container.hgt as i64);
+
+        // Scales length by num / den, rounding to the nearest tile.
+        let scale = |length: i64, num: i64, den: i64| (2 * length * num + den) / (2 * den);
+
+        let (new_wid, new_hgt) = if max_wid * hgt <= max_hgt * wid {
+            let new_wid = wid.min(max_wid);
+            (new_wid, scale(hgt, new_wid, wid).max(1).min(max_hgt))
+        } else {
+            let new_hgt = hgt.min(max_hgt);
+            (scale(wid, new_hgt, hgt).max(1).min(max_wid), new_hgt)
+        };
+
+        Self::new(0, 0, new_wid as i32, new_hgt as i32).align_to(container, Alignment::Center)
+    }
+
+    /// Moves the rect as little as possible so that it lies within bounds, without
+    /// changing its size. If the rect is wider than bounds, it is placed against the
+    /// left edge of bounds, and if it is taller, against the top edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let screen = Rect::new(0, 9, 20, 10);
+    /// let mut window = Rect::new(17, 11, 5, 4);
+    /// window.clamp_within(&screen);
+    ///
+    /// assert_eq!(window, Rect::new(15, 9, 5, 4));
+    /// assert!(screen.contains_rect(&window));
+    /// ```
+    pub fn clamp_within(&mut self, bounds: &Self) {
+        self.left = if self.wid > bounds.wid {
+            bounds.left
+        } else {
+            self.left.max(bounds.left).min(bounds.right() - (self.wid - 1))
+        };
+
+        self.top = if self.hgt > bounds.hgt {
+            bounds.top
+        } else {
+            self.top.min(bounds.top).max(bounds.bottom() + (self.hgt - 1))
+        };
+    }
+
+    /// Returns a copy of the rect moved so that its left edge is gap tiles to the
+    /// right of other's right edge, keeping its `top`. A gap of 0 leaves the rects
+    /// side by side with no space between.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let panel = Rect::new(0, 4, 3, 5);
+    /// let rect = Rect::new(-9, 2, 2, 2);
+    ///
+    /// assert_eq!(rect.snap_right_of(&panel, 0), Rect::new(3, 2, 2, 2));
+    /// assert_eq!(rect.snap_right_of(&panel, 2), Rect::new(5, 2, 2, 2));
+    /// ```
+    pub fn snap_right_of(&self, other: &Self, gap: i32) -> Self {
+        Self::new(other.right() + 1 + gap, self.top, self.wid, self.hgt)
+    }
+
+    /// Returns a copy of the rect moved so that its right edge is gap tiles to the
+    /// left of other's left edge, keeping its `top`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let panel = Rect::new(0, 4, 3, 5);
+    ///
+    /// assert_eq!(Rect::new(-9, 2, 2, 2).snap_left_of(&panel, 1), Rect::new(-3, 2, 2, 2));
+    /// ```
+    pub fn snap_left_of(&self, other: &Self, gap: i32) -> Self {
+        Self::new(other.left - gap - self.wid, self.top, self.wid, self.hgt)
+    }
+
+    /// Returns a copy of the rect moved so that its bottom edge is gap tiles above
+    /// other's top edge, keeping its `left`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let panel = Rect::new(0, 4, 3, 5);
+    ///
+    /// assert_eq!(Rect::new(-9, 2, 2, 2).snap_above(&panel, 0), Rect::new(-9, 6, 2, 2));
+    /// ```
+    pub fn snap_above(&self, other: &Self, gap: i32) -> Self {
+        Self::new(self.left, other.top + gap + self.hgt, self.wid, self.hgt)
+    }
+
+    /// Returns a copy of the rect moved so that its top edge is gap tiles below
+    /// other's bottom edge, keeping its `left`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let panel = Rect::new(0, 4, 3, 5);
+    ///
+    /// assert_eq!(Rect::new(-9, 2, 2, 2).snap_below(&panel, 0), Rect::new(-9, -1, 2, 2));
+    /// ```
+    pub fn snap_below(&self, other: &Self, gap: i32) -> Self {
+        Self::new(self.left, other.bottom() - 1 - gap, self.wid, self.hgt)
+    }
+
+    /// Returns the rect rotated 90 degrees clockwise about its centre, which swaps
+    /// its width and height.
+    ///
+    /// When the width and height are both odd or both even, the rect is rotated
+    /// about its true centre, as given by [`Rect::center_f64`], which is kept
+    /// exactly. Otherwise the rotated rect would not line up with the tiles, so it
+    /// is rotated instead about whichever of the two tiles nearest the true centre
+    /// has an even `x + y`. The rotated rect picks the same tile, so four rotations
+    /// give back the original rect and [`Rect::rotate_90_ccw`] undoes this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 3);
+    ///
+    /// // The above rectangle (before rotating), below:
+    /// // 'C' is the centre.
+    /// //
+    /// // +---+
+    /// // | C |
+    /// // +---+
+    /// //
+    /// // After rotating:
+    /// //
+    /// //  +-+
+    /// //  | |
+    /// //  |C|
+    /// //  | |
+    /// //  +-+
+    ///
+    /// let rotated = rect.rotate_90_cw();
+    ///
+    /// assert_eq!(rotated, Rect::new(1, 5, 3, 5));
+    /// assert_eq!(rotated.center(), rect.center());
+    ///
+    /// // The width is even and the height odd, so this is rotated about (1, 1).
+    /// let rect = Rect::new(0, 2, 4, 3);
+    ///
+    /// assert_eq!(rect.rotate_90_cw(), Rect::new(0, 2, 3, 4));
+    /// ```
+    pub fn rotate_90_cw(&self) -> Self {
+        let (px, py) = self.rotation_pivot();
+        let left = (px + 2 * self.bottom() as i64 - py) / 2;
+        let top = (px + py) / 2 - self.left as i64;
+
+        Self::new(left as i32, top as i32, self.hgt, self.wid)
+    }
+
+    /// Returns the rect rotated 90 degrees anticlockwise about its centre, undoing
+    /// [`Rect::rotate_90_cw`]. The centre is chosen as for [`Rect::rotate_90_cw`],
+    /// so the two only differ when one of the width and height is even and the
+    /// other odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 4, 3);
+    ///
+    /// assert_eq!(rect.rotate_90_ccw(), Rect::new(0, 3, 3, 4));
+    /// assert_eq!(rect.rotate_90_cw().rotate_90_ccw(), rect);
+    ///
+    /// let odd = Rect::new(0, 4, 5, 3);
+    ///
+    /// assert_eq!(odd.rotate_90_ccw(), odd.rotate_90_cw());
+    /// ```
+    pub fn rotate_90_ccw(&self) -> Self {
+        let (px, py) = self.rotation_pivot();
+        let left = (px + py) / 2 - self.top as i64;
+        let top = (py - px + 2 * self.right() as i64) / 2;
+
+        Self::new(left as i32, top as i32, self.hgt, self.wid)
+    }
+
+    /// Returns the point [`Rect::rotate_90_cw`] and [`Rect::rotate_90_ccw`] rotate
+    /// about, with both co-ords doubled so that the true centre can be given when
+    /// it lies between tiles.
+    fn rotation_pivot(&self) -> (i64, i64) {
+        let x = self.left as i64 + self.right() as i64;
+        let y = self.top as i64 + self.bottom() as i64;
+
+        if (x - y) % 2 == 0 {
+            (x, y)
+        } else if x % 2 != 0 {
+            // Of the tiles either side, take the one with an even x + y.
+            let tile = (x - 1).div_euclid(2);
+            let tile = if (tile + y / 2) % 2 == 0 { tile } else { tile + 1 };
+            (tile * 2, y)
+        } else {
+            let tile = (y - 1).div_euclid(2);
+            let tile = if (x / 2 + tile) % 2 == 0 { tile } else { tile + 1 };
+            (x, tile * 2)
+        }
+    }
+
+    /// Returns the rect mirrored across the vertical line through column axis_x,
+    /// keeping its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 0, 3, 2);
+    ///
+    /// // The above rectangle (before flipping), below:
+    /// // 'O' is the origin, which is on the axis.
+    /// //
+    /// //  +-+
+    /// // O+-+
+    /// //
+    /// // After flipping:
+    /// //
+    /// // +-+
+    /// // +-+O
+    ///
+    /// assert_eq!(rect.flip_horizontal(0), Rect::new(-3, 0, 3, 2));
+    /// ```
+    pub fn flip_horizontal(&self, axis_x: i32) -> Self {
+        Self::new(2 * axis_x - self.right(), self.top, self.wid, self.hgt)
+    }
+
+    /// Returns the rect mirrored across the horizontal line through row axis_y,
+    /// keeping its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 3, 3, 2);
+    ///
+    /// assert_eq!(rect.flip_vertical(0), Rect::new(1, -2, 3, 2));
+    /// ```
+    pub fn flip_vertical(&self, axis_y: i32) -> Self {
+        Self::new(self.left, 2 * axis_y - self.bottom(), self.wid, self.hgt)
+    }
+
+    /// Splits the rect into a top and bottom rect along row at_y, which becomes
+    /// the top row of the bottom rect. Returns None if either rect would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 3, 5);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// // '-' is the split row.
+    /// //
+    /// // +-+
+    /// // | |
+    /// // ---
+    /// // | |
+    /// // O-+
+    ///
+    /// assert_eq!(rect.split_horizontal(2), Some((Rect::new(0, 4, 3, 2), Rect::new(0, 2, 3, 3))));
+    /// assert_eq!(rect.split_horizontal(4), None);
+    /// ```
+    pub fn split_horizontal(&self, at_y: i32) -> Option<(Self, Self)> {
+        if at_y >= self.top || at_y < self.bottom() {
+            return None;
+        }
+
+        let top_hgt = self.top - at_y;
+
+        Some((
+            Self::new(self.left, self.top, self.wid, top_hgt),
+            Self::new(self.left, at_y, self.wid, self.hgt - top_hgt),
+        ))
+    }
+
+    /// Splits the rect into a left and right rect along column at_x, which becomes
+    /// the left column of the right rect. Returns None if either rect would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 5, 3);
+    ///
+    /// // The above rectangle, below:
+    /// // 'O' is the origin.
+    /// // '|' is the split column.
+    /// //
+    /// // +-|-+
+    /// // | | |
+    /// // O-|-+
+    ///
+    /// assert_eq!(rect.split_vertical(2), Some((Rect::new(0, 2, 2, 3), Rect::new(2, 2, 3, 3))));
+    /// assert_eq!(rect.split_vertical(0), None);
+    /// ```
+    pub fn split_vertical(&self, at_x: i32) -> Option<(Self, Self)> {
+        if at_x <= self.left || at_x > self.right() {
+            return None;
+        }
+
+        let left_wid = at_x - self.left;
+
+        Some((
+            Self::new(self.left, self.top, left_wid, self.hgt),
+            Self::new(at_x, self.top, self.wid - left_wid, self.hgt),
+        ))
+    }
+
+    /// Divides the rect into a grid of cols by rows rects, which together cover
+    /// the rect exactly. Returned top to bottom, left to right.
+    ///
+    /// When the width or height does not divide evenly, the leftmost columns and
+    /// topmost rows are made one tile larger. Returns an empty `Vec` if cols or rows
+    /// is 0, or if there are more columns than the rect is wide or more rows than it
+    /// is tall, as some rects would then have no tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 5, 3);
+    ///
+    /// let expected = vec![
+    ///     Rect::new(0, 2, 3, 2),
+    ///     Rect::new(3, 2, 2, 2),
+    ///     Rect::new(0, 0, 3, 1),
+    ///     Rect::new(3, 0, 2, 1),
+    /// ];
+    ///
+    /// assert_eq!(rect.subdivide(2, 2), expected);
+    /// ```
+    pub fn subdivide(&self, cols: u32, rows: u32) -> Vec<Self> {
+        if cols == 0 || rows == 0 || cols as i64 > self.wid as i64 || rows as i64 > self.hgt as i64 {
+            return Vec::new();
+        }
+
+        // Both now fit in an i32, as they are no larger than the width and height.
+        let mut rects = Vec::with_capacity((cols as usize).saturating_mul(rows as usize));
+        let cols = cols as i32;
+        let rows = rows as i32;
+        let mut top = self.top;
+
+        for row in 0..rows {
+            let hgt = self.hgt / rows + i32::from(row < self.hgt % rows);
+            let mut left = self.left;
+
+            for col in 0..cols {
+                let wid = self.wid / cols + i32::from(col < self.wid % cols);
+                rects.push(Self::new(left, top, wid, hgt));
+                left += wid;
+            }
+
+            top -= hgt;
+        }
+
+        rects
+    }
+
+    /// Splits the rect into a top and bottom part, with the top part taking ratio of
+    /// the height, rounded to the nearest tile. The ratio is clamped to between 0
+    /// and 1, so a ratio of 0 gives an empty top part and the whole rect as the
+    /// bottom part, and a ratio of 1 the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 9, 4, 10);
+    ///
+    /// assert_eq!(rect.split_at_ratio_horizontal(0.3), (Rect::new(0, 9, 4, 3), Rect::new(0, 6, 4, 7)));
+    /// assert_eq!(rect.split_at_ratio_horizontal(1.0), (rect, Rect::new(0, -1, 4, 0)));
+    /// ```
+    pub fn split_at_ratio_horizontal(&self, ratio: f64) -> (Self, Self) {
+        let top_hgt = (self.hgt as f64 * ratio.clamp(0.0, 1.0)).round() as i32;
+
+        (
+            Self::new(self.left, self.top, self.wid, top_hgt),
+            Self::new(self.left, self.top - top_hgt, self.wid, self.hgt - top_hgt),
+        )
+    }
+
+    /// Splits the rect into a left and right part, with the left part taking ratio
+    /// of the width, rounded to the nearest tile. The ratio is clamped to between 0
+    /// and 1, so a ratio of 0 gives an empty left part and the whole rect as the
+    /// right part, and a ratio of 1 the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 10, 5);
+    ///
+    /// // A sidebar taking 30% of the width, and the main area.
+    /// assert_eq!(rect.split_at_ratio_vertical(0.3), (Rect::new(0, 4, 3, 5), Rect::new(3, 4, 7, 5)));
+    /// assert_eq!(rect.split_at_ratio_vertical(0.0), (Rect::new(0, 4, 0, 5), rect));
+    /// ```
+    pub fn split_at_ratio_vertical(&self, ratio: f64) -> (Self, Self) {
+        let left_wid = (self.wid as f64 * ratio.clamp(0.0, 1.0)).round() as i32;
+
+        (
+            Self::new(self.left, self.top, left_wid, self.hgt),
+            Self::new(self.left + left_wid, self.top, self.wid - left_wid, self.hgt),
+        )
+    }
+
+    /// Cuts the rect into n bands of the full width, like [`Rect::split_horizontal`]
+    /// repeated, returned from top to bottom. When the height does not divide
+    /// evenly, the topmost bands are made one tile taller. Returns an empty `Vec`
+    /// if n is 0 or more than the height, as in [`Rect::subdivide`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 3, 5);
+    ///
+    /// assert_eq!(
+    ///     rect.split_evenly_horizontal(2),
+    ///     vec![Rect::new(0, 4, 3, 3), Rect::new(0, 1, 3, 2)]
+    /// );
+    /// ```
+    pub fn split_evenly_horizontal(&self, n: u32) -> Vec<Self> {
+        self.subdivide(1, n)
+    }
+
+    /// Cuts the rect into n columns of the full height, like [`Rect::split_vertical`]
+    /// repeated, returned from left to right. When the width does not divide evenly,
+    /// the leftmost columns are made one tile wider. Returns an empty `Vec` if n is 0
+    /// or more than the width, as in [`Rect::subdivide`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 4, 5, 3);
+    ///
+    /// assert_eq!(
+    ///     rect.split_evenly_vertical(2),
+    ///     vec![Rect::new(0, 4, 3, 3), Rect::new(3, 4, 2, 3)]
+    /// );
+    /// ```
+    pub fn split_evenly_vertical(&self, n: u32) -> Vec<Self> {
+        self.subdivide(n, 1)
+    }
+
+    /// Splits the rect into four quarters, returned in the order top left, top
+    /// right, bottom left, bottom right. Together they cover the rect exactly.
+    ///
+    /// As with [`Rect::subdivide`], an odd width makes the left quarters one tile
+    /// wider and an odd height makes the top quarters one tile taller. Rects one
+    /// tile wide or tall give empty right or bottom quarters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(0, 2, 5, 3);
+    ///
+    /// // The above rectangle split into quarters, below:
+    /// // Each letter marks the tiles of one quarter.
+    /// //
+    /// // aaabb
+    /// // aaabb
+    /// // cccdd
+    ///
+    /// let [top_left, top_right, bottom_left, bottom_right] = rect.quadrants();
+    ///
+    /// assert_eq!(top_left, Rect::new(0, 2, 3, 2));
+    /// assert_eq!(top_right, Rect::new(3, 2, 2, 2));
+    /// assert_eq!(bottom_left, Rect::new(0, 0, 3, 1));
+    /// assert_eq!(bottom_right, Rect::new(3, 0, 2, 1));
+    /// ```
+    pub fn quadrants(&self) -> [Self; 4] {
+        let left_wid = self.wid - self.wid / 2;
+        let top_hgt = self.hgt - self.hgt / 2;
+        let mid_x = self.left + left_wid;
+        let mid_y = self.top - top_hgt;
+
+        [
+            Self::new(self.left, self.top, left_wid, top_hgt),
+            Self::new(mid_x, self.top, self.wid / 2, top_hgt),
+            Self::new(self.left, mid_y, left_wid, self.hgt / 2),
+            Self::new(mid_x, mid_y, self.wid / 2, self.hgt / 2),
+        ]
+    }
+
+    /// Returns an iterator over each row of the rect as a rect one tile tall,
+    /// from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 4, 3);
+    ///
+    /// let expected = vec![Rect::new(1, 1, 4, 1), Rect::new(1, 0, 4, 1), Rect::new(1, -1, 4, 1)];
+    ///
+    /// assert_eq!(rect.rows().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = Self> + use<> {
+        let rect = *self;
+
+        (0..rect.hgt.max(0)).map(move |i| Self::new(rect.left, rect.top - i, rect.wid, 1))
+    }
+
+    /// Returns an iterator over each column of the rect as a rect one tile wide,
+    /// from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::Rect;
+    ///
+    /// let rect = Rect::new(1, 1, 3, 4);
+    ///
+    /// let expected = vec![Rect::new(1, 1, 1, 4), Rect::new(2, 1, 1, 4), Rect::new(3, 1, 1, 4)];
+    ///
+    /// assert_eq!(rect.columns().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = Self> + use<> {
+        let rect = *self;
+
+        (0..rect.wid.max(0)).map(move |i| Self::new(rect.left + i, rect.top, 1, rect.hgt))
+    }
+}
+
+/// Formats the rect as `Rect(left=<left>, top=<top>, <wid>x<hgt>)`.
+///
+/// # Examples
+///
+/// ```
+/// use rect::Rect;
+///
+/// let rect = Rect::new(1, 5, 3, 4);
+///
+/// assert_eq!(rect.to_string(), "Rect(left=1, top=5, 3x4)");
+/// ```
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rect(left={}, top={}, {}x{})", self.left, self.top, self.wid, self.hgt)
+    }
+}
+
+/// Iterates over every cell of the rect, as with [`Rect::cells`].
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use rect::Rect;
+///
+/// let rect = Rect::new(0, 1, 2, 2);
+/// let mut visited = Vec::new();
+///
+/// for pos in rect {
+///     visited.push(pos);
+/// }
+///
+/// assert_eq!(visited, vec![Point::new(0, 1), Point::new(1, 1), Point::new(0, 0), Point::new(1, 0)]);
+/// ```
+impl IntoIterator for Rect {
+    type Item = Point;
+    type IntoIter = InteriorIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells()
+    }
+}
+
+impl IntoIterator for &Rect {
+    type Item = Point;
+    type IntoIter = InteriorIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells()
+    }
+}
+
+/// Moves the rect by the given offset, as with [`Rect::translated`].
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use rect::Rect;
+///
+/// let rect = Rect::new(1, 1, 3, 2);
+/// let offset = Point::new(1, 0);
+///
+/// assert_eq!(rect + offset, Rect::new(2, 1, 3, 2));
+/// assert_eq!(rect - offset, Rect::new(0, 1, 3, 2));
+/// assert_eq!(rect + offset - offset, rect);
+/// ```
+impl Add<Point> for Rect {
+    type Output = Self;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        self.translated(rhs)
+    }
+}
+
+impl AddAssign<Point> for Rect {
+    fn add_assign(&mut self, rhs: Point) {
+        self.move_by(rhs);
+    }
+}
+
+impl Sub<Point> for Rect {
+    type Output = Self;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        self.translated(Point::new(-rhs.x, -rhs.y))
+    }
+}
+
+impl SubAssign<Point> for Rect {
+    fn sub_assign(&mut self, rhs: Point) {
+        self.move_by(Point::new(-rhs.x, -rhs.y));
+    }
+}
+
+/// Error returned when parsing a rect from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseRectError {
+    /// The string did not contain exactly four comma separated fields.
+    /// Holds the number of fields found.
+    WrongFieldCount(usize),
+    /// One of the fields was not a valid integer.
+    InvalidInt(ParseIntError),
+}
+
+impl fmt::Display for ParseRectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WrongFieldCount(n) => write!(f, "expected 4 fields, found {n}"),
+            Self::InvalidInt(e) => write!(f, "invalid integer: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::WrongFieldCount(_) => None,
+            Self::InvalidInt(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseRectError {
+    fn from(val: ParseIntError) -> Self {
+        Self::InvalidInt(val)
+    }
+}
+
+/// Parses a rect from the form `left,top,wid,hgt`. Whitespace around each field is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rect::{ParseRectError, Rect};
+///
+/// assert_eq!("1, 5, 3, 4".parse::<Rect>(), Ok(Rect::new(1, 5, 3, 4)));
+/// assert_eq!("1,5,3".parse::<Rect>(), Err(ParseRectError::WrongFieldCount(3)));
+/// ```
+impl FromStr for Rect {
+    type Err = ParseRectError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split(',').map(str::trim).collect::<Vec<_>>();
+
+        if fields.len() != 4 {
+            return Err(ParseRectError::WrongFieldCount(fields.len()));
+        }
+
+        Ok(Self::new(
+            fields[0].parse()?,
+            fields[1].parse()?,
+            fields[2].parse()?,
+            fields[3].parse()?,
+        ))
+    }
+}
+
+/// Error returned when a rect would have a width or height of zero or less.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DimensionError {
+    /// The width was not positive. Holds the width given.
+    Width(i32),
+    /// The height was not positive. Holds the height given.
+    Height(i32),
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Width(wid) => write!(f, "width must be positive, found {wid}"),
+            Self::Height(hgt) => write!(f, "height must be positive, found {hgt}"),
+        }
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+/// Creates a rect from `(left, top, wid, hgt)`, failing if the width or height is
+/// not positive. The width is checked first.
+///
+/// # Examples
+///
+/// ```
+/// use rect::{DimensionError, Rect};
+///
+/// assert_eq!(Rect::try_from((1, 5, 3, 4)), Ok(Rect::new(1, 5, 3, 4)));
+/// assert_eq!(Rect::try_from((1, 5, 0, 4)), Err(DimensionError::Width(0)));
+/// ```
+impl TryFrom<(i32, i32, i32, i32)> for Rect {
+    type Error = DimensionError;
+
+    fn try_from(val: (i32, i32, i32, i32)) -> Result<Self, Self::Error> {
+        let (left, top, wid, hgt) = val;
+
+        if wid <= 0 {
+            return Err(DimensionError::Width(wid));
+        }
+
+        if hgt <= 0 {
+            return Err(DimensionError::Height(hgt));
+        }
+
+        Ok(Self::new(left, top, wid, hgt))
+    }
+}
+
+/// Converts the rect into `(left, top, wid, hgt)`.
+///
+/// There is no unchecked conversion back, as it would conflict with the validating
+/// `TryFrom` impl; use [`Rect::new`] for that instead.
+///
+/// # Examples
+///
+/// ```
+/// use rect::Rect;
+///
+/// let rect = Rect::new(1, 5, 3, 4);
+/// let tuple: (i32, i32, i32, i32) = rect.into();
+///
+/// assert_eq!(tuple, (1, 5, 3, 4));
+/// assert_eq!(Rect::try_from(tuple), Ok(rect));
+/// ```
+impl From<Rect> for (i32, i32, i32, i32) {
+    fn from(val: Rect) -> Self {
+        (val.left, val.top, val.wid, val.hgt)
+    }
+}
+
+/// Converts the rect into its minimum corner and size, as glam vectors.
+///
+/// The y axis is not flipped: like the rect, y increases upwards, so the minimum
+/// corner is the bottom left. Code using glam with y increasing downwards must
+/// negate the y co-ords itself.
+///
+/// # Examples
+///
+/// ```
+/// use glam::IVec2;
+/// use rect::Rect;
+///
+/// let rect = Rect::new(1, 5, 3, 4);
+/// let (min, size): (IVec2, IVec2) = rect.into();
+///
+/// assert_eq!(min, IVec2::new(1, 2));
+/// assert_eq!(size, IVec2::new(3, 4));
+/// assert_eq!(Rect::from((min, size)), rect);
+/// ```
+#[cfg(feature = "glam")]
+impl From<Rect> for (glam::IVec2, glam::IVec2) {
+    fn from(val: Rect) -> Self {
+        (glam::IVec2::new(val.left, val.bottom()), glam::IVec2::new(val.wid, val.hgt))
+    }
+}
+
+/// Creates a rect from its minimum corner and size, as glam vectors.
+/// Uses the same y axis as the conversion the other way.
+#[cfg(feature = "glam")]
+impl From<(glam::IVec2, glam::IVec2)> for Rect {
+    fn from(val: (glam::IVec2, glam::IVec2)) -> Self {
+        let (min, size) = val;
+
+        Self::new(min.x, min.y + (size.y - 1), size.x, size.y)
+    }
+}
+
+/// A rect that serializes as the array `[left, top, wid, hgt]`, rather than as a
+/// map of named fields like [`Rect`] itself, for when size matters more than
+/// readability. To use this form for a `Rect` field without wrapping it, use
+/// `#[serde(with = "rect::serde_array")]`.
+///
+/// # Examples
+///
+/// ```
+/// use rect::{Rect, RectArray};
+///
+/// let json = serde_json::to_string(&RectArray(Rect::new(1, 2, 3, 4))).unwrap();
+///
+/// assert_eq!(json, "[1,2,3,4]");
+/// assert_eq!(serde_json::from_str::<RectArray>(&json).unwrap(), RectArray(Rect::new(1, 2, 3, 4)));
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RectArray(pub Rect);
+
+#[cfg(feature = "serde")]
+impl From<Rect> for RectArray {
+    fn from(val: Rect) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RectArray> for Rect {
+    fn from(val: RectArray) -> Self {
+        val.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RectArray {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_array::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RectArray {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_array::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serializes and deserializes a [`Rect`] as the array `[left, top, wid, hgt]`,
+/// for use with `#[serde(with = "rect::serde_array")]`. See [`RectArray`].
+#[cfg(feature = "serde")]
+pub mod serde_array {
+    use super::Rect;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the rect as `[left, top, wid, hgt]`.
+    pub fn serialize<S: Serializer>(rect: &Rect, serializer: S) -> Result<S::Ok, S::Error> {
+        (rect.left, rect.top, rect.wid, rect.hgt).serialize(serializer)
+    }
+
+    /// Deserializes a rect from `[left, top, wid, hgt]`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rect, D::Error> {
+        let (left, top, wid, hgt) = <(i32, i32, i32, i32)>::deserialize(deserializer)?;
+
+        Ok(Rect::new(left, top, wid, hgt))
+    }
+}
+
+/// An iterator over the cells inside a rect.
+/// Iterates top to bottom, left to right.
+#[derive(Clone, Debug)]
+pub struct InteriorIter {
+    cur_pos: Point,
+    rect: Rect,
+    end: bool,
+}
+
+impl Iterator for InteriorIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end {
+            return None;
+        }
+
+        let ret = self.cur_pos;
+
+        let mut new_x = self.cur_pos.x + 1;
+
+        // Check if end of row.
+        if new_x > self.rect.right() {
+            new_x = self.rect.left;
+            self.cur_pos.y -= 1;
+
+            // Check if end of rectangle.
+            if self.cur_pos.y < self.rect.bottom() {
+                self.end = true;
+            } else {
+                self.cur_pos.x = new_x;
+            }
+        }
+
+        self.cur_pos.x = new_x;
+
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.end {
+            return (0, Some(0));
+        }
+
+        // Cells left in the current row, plus every full row below it.
+        let row = (self.rect.right() - self.cur_pos.x + 1) as usize;
+        let rows_below = (self.cur_pos.y - self.rect.bottom()) as usize;
+        let len = row + rows_below * self.rect.wid as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for InteriorIter {}
+
+impl FusedIterator for InteriorIter {}
+
+impl From<Rect> for InteriorIter {
+    fn from(val: Rect) -> Self {
+        Self {
+            cur_pos: val.top_left(),
+            rect: val,
+            // A rect with no width or height has no cells to yield.
+            end: val.wid <= 0 || val.hgt <= 0,
+        }
+    }
+}
+
+/// An iterator over the cells along the edges of a rect clockwise, starting from the top left.
+/// Each cell is yielded exactly once, so the top left corner is not revisited at the end.
+#[derive(Clone, Debug)]
+pub struct EdgeIter {
+    cur_pos: Point,
+	dir: Point,
+	corners: [Point; 4],
+	// Number of cells left to yield.
+	remaining: usize,
+}
+
+impl Iterator for EdgeIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		
+        let ret = self.cur_pos;
+		self.remaining -= 1;
+		
+		self.cur_pos = self.cur_pos + self.dir;
+		
+		if self.corners.contains(&self.cur_pos) {
+			self.dir.rotate_90_cw_ip();
+		}
+
+        Some(ret)
+    }
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for EdgeIter {}
+
+impl FusedIterator for EdgeIter {}
+
+impl From<&Rect> for EdgeIter {
+    fn from(val: &Rect) -> Self {
+		// A rect one tile wide is a single column, so walk straight down it.
+		let dir = if val.wid == 1 {
+			Point::new(0, -1)
+		} else {
+			Point::new(1, 0)
+		};
+		
+        Self {
+            cur_pos: val.top_left(),
+			dir,
+			corners: val.corners(),
+			remaining: val.exact_perimeter() as usize,
+        }
+    }
+}
+
+/// A rectangle with `i64` co-ords, for worlds too large for [`Rect`].
+///
+/// [`Point`] only holds `i32` co-ords, so positions are taken and returned as
+/// `(x, y)` tuples instead. As with [`Rect`], y increases upwards, and every tile
+/// of the rect, including [`RectI64::right`] and [`RectI64::bottom`], must fit in
+/// an `i64`.
+///
+/// This covers construction, corners, containment, overlap, intersection and
+/// union, moving, expanding and shrinking, centring and alignment, the cell and
+/// edge iterators, [`fmt::Display`] and [`FromStr`], serde, and the rand and glam
+/// helpers of [`Rect`]. The splitting, distance, line and scaling methods are not
+/// provided; convert to a [`Rect`] with [`TryFrom`] where the rect is small enough.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RectI64 {
+    /// Largest y co-ord of the rect.
+    pub top: i64,
+    /// Farthest left x co-ord of the rect.
+    pub left: i64,
+    /// Width of the rect in tiles.
+    pub wid: i64,
+    /// Height of the rect in tiles.
+    pub hgt: i64,
+}
+
+impl RectI64 {
+    /// A rect at the origin with no width or height, which is also the default rect.
+    pub const EMPTY: Self = Self::new(0, 0, 0, 0);
+
+    /// Create a new rectangle.
+    pub const fn new(left: i64, top: i64, wid: i64, hgt: i64) -> Self {
+        Self {
+            top,
+            left,
+            wid,
+            hgt,
+        }
+    }
+
+    /// Create a new rectangle, or None if the width or height is not positive, or
+    /// if [`RectI64::right`] or [`RectI64::bottom`] would not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// assert_eq!(RectI64::checked_new(1, 5, 3, 4), Some(RectI64::new(1, 5, 3, 4)));
+    /// assert_eq!(RectI64::checked_new(i64::MAX, 5, 2, 4), None);
+    /// ```
+    pub const fn checked_new(left: i64, top: i64, wid: i64, hgt: i64) -> Option<Self> {
+        if wid <= 0 || hgt <= 0 {
+            return None;
+        }
+
+        if left.checked_add(wid - 1).is_none() || top.checked_sub(hgt - 1).is_none() {
+            return None;
+        }
+
+        Some(Self::new(left, top, wid, hgt))
+    }
+
+    /// Rightmost x co-ord of the rect.
+    pub const fn right(&self) -> i64 {
+        self.left + (self.wid - 1)
+    }
+
+    /// Bottommost y co-ord of the rect.
+    pub const fn bottom(&self) -> i64 {
+        self.top - (self.hgt - 1)
+    }
+
+    /// Returns true if the rect has no width or no height.
+    pub const fn is_empty(&self) -> bool {
+        self.wid <= 0 || self.hgt <= 0
+    }
+
+    /// Returns the area of the rectangle. Every area of a rect with `i64`
+    /// dimensions fits in a `u128`, and negative dimensions count by their length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// assert_eq!(RectI64::new(0, 0, 1 << 40, 1 << 40).area(), 1 << 80);
+    /// ```
+    pub const fn area(&self) -> u128 {
+        self.wid.unsigned_abs() as u128 * self.hgt.unsigned_abs() as u128
+    }
+
+    /// Checks whether the position (x, y) is within or on the rectangle's boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let rect = RectI64::new(5_000_000_000, 0, 3, 5);
+    ///
+    /// assert!(rect.contains(5_000_000_002, -4));
+    /// assert!(!rect.contains(5_000_000_003, -4));
+    /// ```
+    pub const fn contains(&self, x: i64, y: i64) -> bool {
+        // Widened so that rects running past the edge of the i64 range do not overflow.
+        let dx = x as i128 - self.left as i128;
+        let dy = self.top as i128 - y as i128;
+
+        dx >= 0 && dx < self.wid as i128 && dy >= 0 && dy < self.hgt as i128
+    }
+
+    /// Checks whether every tile of other is within or on the rectangle's boundaries.
+    pub const fn contains_rect(&self, other: &Self) -> bool {
+        self.left <= other.left
+            && self.right() >= other.right()
+            && self.top >= other.top
+            && self.bottom() <= other.bottom()
+    }
+
+    /// Returns true if the rect overlaps other. Empty rects never overlap anything.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.left <= other.right()
+            && self.right() >= other.left
+            && self.top >= other.bottom()
+            && self.bottom() <= other.top
+    }
+
+    /// Returns the region shared by both rects, or None if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let rect1 = RectI64::new(0, 7, 4, 3);
+    /// let rect2 = RectI64::new(3, 6, 5, 5);
+    ///
+    /// assert_eq!(rect1.intersection(&rect2), Some(RectI64::new(3, 6, 1, 2)));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let left = self.left.max(other.left);
+        let right = self.right().min(other.right());
+        let top = self.top.min(other.top);
+        let bottom = self.bottom().max(other.bottom());
+
+        if left > right || bottom > top {
+            return None;
+        }
+
+        Some(Self::new(left, top, right - left + 1, top - bottom + 1))
+    }
+
+    /// Returns the smallest rect containing both rects.
+    pub fn union(&self, other: &Self) -> Self {
+        let left = self.left.min(other.left);
+        let right = self.right().max(other.right());
+        let top = self.top.max(other.top);
+        let bottom = self.bottom().min(other.bottom());
+
+        Self::new(left, top, right - left + 1, top - bottom + 1)
+    }
+
+    /// Returns a copy of the rect moved by (dx, dy).
+    pub const fn translated(&self, dx: i64, dy: i64) -> Self {
+        Self::new(self.left + dx, self.top + dy, self.wid, self.hgt)
+    }
+
+    /// Relocates the rect's top left corner to (x, y).
+    pub fn move_to(&mut self, x: i64, y: i64) {
+        self.left = x;
+        self.top = y;
+    }
+
+    /// Moves the rect by (dx, dy), leaving its size unchanged.
+    pub fn move_by(&mut self, dx: i64, dy: i64) {
+        self.left += dx;
+        self.top += dy;
+    }
+
+    /// Returns the top left corner.
+    pub const fn top_left(&self) -> (i64, i64) {
+        (self.left, self.top)
+    }
+
+    /// Returns the top right corner.
+    pub const fn top_right(&self) -> (i64, i64) {
+        (self.right(), self.top)
+    }
+
+    /// Returns the bottom left corner.
+    pub const fn bottom_left(&self) -> (i64, i64) {
+        (self.left, self.bottom())
+    }
+
+    /// Returns the bottom right corner.
+    pub const fn bottom_right(&self) -> (i64, i64) {
+        (self.right(), self.bottom())
+    }
+
+    /// Returns each corner of the rect, in the order top left, top right, bottom left,
+    /// bottom right, as with [`Rect::corners`].
+    pub const fn corners(&self) -> [(i64, i64); 4] {
+        [self.top_left(), self.top_right(), self.bottom_left(), self.bottom_right()]
+    }
+
+    /// Increases the size of the rectangle by (dx, dy), as with [`Rect::expand`]. A
+    /// negative dx moves the left edge left, and a positive dy moves the top edge up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let mut rect = RectI64::new(1, 1, 3, 5);
+    /// rect.expand(-2, 1);
+    ///
+    /// assert_eq!(rect, RectI64::new(-1, 2, 5, 6));
+    /// ```
+    pub fn expand(&mut self, dx: i64, dy: i64) {
+        self.wid += dx.abs();
+
+        if dx < 0 {
+            self.left += dx;
+        }
+
+        self.hgt += dy.abs();
+
+        if dy > 0 {
+            self.top += dy;
+        }
+    }
+
+    /// Returns a copy of the rect increased in size by (dx, dy), as with
+    /// [`RectI64::expand`].
+    pub fn expanded(&self, dx: i64, dy: i64) -> Self {
+        let mut rect = *self;
+        rect.expand(dx, dy);
+        rect
+    }
+
+    /// Decreases the size of the rectangle, undoing an [`RectI64::expand`] by the
+    /// same amount, as with [`Rect::shrink`]. If shrinking would make a dimension
+    /// negative, it is clamped to zero instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let mut rect = RectI64::new(-1, 2, 5, 6);
+    /// rect.shrink(-2, 1);
+    ///
+    /// assert_eq!(rect, RectI64::new(1, 1, 3, 5));
+    ///
+    /// rect.shrink(0, -10);
+    ///
+    /// assert_eq!(rect, RectI64::new(1, 1, 3, 0));
+    /// ```
+    pub fn shrink(&mut self, dx: i64, dy: i64) {
+        let dx_clamped = dx.abs().min(self.wid.max(0));
+        self.wid -= dx_clamped;
+
+        if dx < 0 {
+            self.left += dx_clamped;
+        }
+
+        let dy_clamped = dy.abs().min(self.hgt.max(0));
+        self.hgt -= dy_clamped;
+
+        if dy > 0 {
+            self.top -= dy_clamped;
+        }
+    }
+
+    /// Returns the centre of the rect. As with [`Rect::center`], when the rect has
+    /// no exact centre tile, the centre is taken to the right of and/or below the
+    /// true centre.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// assert_eq!(RectI64::new(0, 2, 3, 3).center(), (1, 1));
+    /// assert_eq!(RectI64::new(0, 4, 4, 4).center(), (2, 2));
+    /// ```
+    pub const fn center(&self) -> (i64, i64) {
+        (self.left + self.wid / 2, self.top - self.hgt / 2)
+    }
+
+    /// Moves the rect so that [`RectI64::center`] is (x, y), as with [`Rect::centre_on`].
+    pub fn centre_on(&mut self, x: i64, y: i64) {
+        self.move_to(x - self.wid / 2, y + self.hgt / 2);
+    }
+
+    /// Returns a copy of the rect placed within container according to align,
+    /// keeping its size, as with [`Rect::align_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::{Alignment, RectI64};
+    ///
+    /// let container = RectI64::new(0, 5, 7, 6);
+    /// let rect = RectI64::new(10, 10, 3, 2);
+    ///
+    /// assert_eq!(rect.align_to(&container, Alignment::Center), RectI64::new(2, 3, 3, 2));
+    /// assert_eq!(rect.align_to(&container, Alignment::BottomRight), RectI64::new(4, 1, 3, 2));
+    /// ```
+    pub fn align_to(&self, container: &Self, align: Alignment) -> Self {
+        let left = match align {
+            Alignment::TopLeft | Alignment::Left | Alignment::BottomLeft => container.left,
+            Alignment::Top | Alignment::Center | Alignment::Bottom => {
+                container.left + container.wid / 2 - self.wid / 2
+            }
+            Alignment::TopRight | Alignment::Right | Alignment::BottomRight => {
+                container.left + container.wid - self.wid
+            }
+        };
+        let top = match align {
+            Alignment::TopLeft | Alignment::Top | Alignment::TopRight => container.top,
+            Alignment::Left | Alignment::Center | Alignment::Right => {
+                container.top - container.hgt / 2 + self.hgt / 2
+            }
+            Alignment::BottomLeft | Alignment::Bottom | Alignment::BottomRight => {
+                container.top - container.hgt + self.hgt
+            }
+        };
+
+        Self::new(left, top, self.wid, self.hgt)
+    }
+
+    /// Returns an iterator over all positions contained within the rect, including
+    /// the edges. Iterates top to bottom, left to right, as with [`Rect::cells`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let rect = RectI64::new(0, 1, 2, 2);
+    ///
+    /// assert_eq!(rect.cells().collect::<Vec<_>>(), [(0, 1), (1, 1), (0, 0), (1, 0)]);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (i64, i64)> + use<> {
+        let (left, right) = (self.left, self.right());
+
+        // An empty rect has its right edge left of its left edge, or its bottom edge
+        // above its top edge, so one of the ranges is empty.
+        (self.bottom()..=self.top).rev().flat_map(move |y| (left..=right).map(move |x| (x, y)))
+    }
+
+    /// Returns an iterator over the cells along the edges of the rect clockwise,
+    /// starting from the top left, as with [`Rect::edges`]. Each cell is yielded
+    /// exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let rect = RectI64::new(1, 1, 3, 3);
+    /// let expected = [(1, 1), (2, 1), (3, 1), (3, 0), (3, -1), (2, -1), (1, -1), (1, 0)];
+    ///
+    /// assert_eq!(rect.edges().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = (i64, i64)> + use<> {
+        let (left, right, top, bottom) = (self.left, self.right(), self.top, self.bottom());
+        let (tall, wide) = (self.hgt > 1, self.wid > 1);
+
+        let top_edge = (left..=right).map(move |x| (x, top));
+        let right_edge = (bottom..top).rev().map(move |y| (right, y));
+        let bottom_edge = (left..right).rev().filter(move |_| tall).map(move |x| (x, bottom));
+        let left_edge = (bottom + 1..top).filter(move |_| wide).map(move |y| (left, y));
+
+        top_edge
+            .chain(right_edge)
+            .chain(bottom_edge)
+            .chain(left_edge)
+            .take(if self.is_empty() { 0 } else { usize::MAX })
+    }
+
+    /// Returns a uniformly chosen position within or on the rectangle's boundaries,
+    /// or None if the rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rect::RectI64;
+    ///
+    /// let rect = RectI64::new(5_000_000_000, 0, 3, 5);
+    /// let (x, y) = rect.random_point(&mut rand::rng()).unwrap();
+    ///
+    /// assert!(rect.contains(x, y));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_point<R: rand::Rng>(&self, rng: &mut R) -> Option<(i64, i64)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some((
+            rng.random_range(self.left..=self.right()),
+            rng.random_range(self.bottom()..=self.top),
+        ))
+    }
+
+    /// Returns the bottom left and top right tiles of the rect as glam vectors, as
+    /// with [`Rect::to_glam_min_max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use glam::I64Vec2;
+    /// use rect::RectI64;
+    ///
+    /// let rect = RectI64::new(1, 5, 3, 4);
+    ///
+    /// assert_eq!(rect.to_glam_min_max(), (I64Vec2::new(1, 2), I64Vec2::new(3, 5)));
+    /// ```
+    #[cfg(feature = "glam")]
+    pub fn to_glam_min_max(&self) -> (glam::I64Vec2, glam::I64Vec2) {
+        (
+            glam::I64Vec2::new(self.left, self.bottom()),
+            glam::I64Vec2::new(self.right(), self.top),
+        )
+    }
+}
+
+/// Formats the rect as with [`Rect`]'s [`fmt::Display`].
+///
+/// # Examples
+///
+/// ```
+/// use rect::RectI64;
+///
+/// let rect = RectI64::new(1, 5_000_000_000, 3, 4);
+///
+/// assert_eq!(rect.to_string(), "RectI64(left=1, top=5000000000, 3x4)");
+/// ```
+impl fmt::Display for RectI64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RectI64(left={}, top={}, {}x{})", self.left, self.top, self.wid, self.hgt)
+    }
+}
+
+/// Parses a rect from the form `left,top,wid,hgt`, as with [`Rect`]'s [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// use rect::{ParseRectError, RectI64};
+///
+/// assert_eq!("1, 5000000000, 3, 4".parse::<RectI64>(), Ok(RectI64::new(1, 5_000_000_000, 3, 4)));
+/// assert_eq!("1,5,3".parse::<RectI64>(), Err(ParseRectError::WrongFieldCount(3)));
+/// ```
+impl FromStr for RectI64 {
+    type Err = ParseRectError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split(',').map(str::trim).collect::<Vec<_>>();
+
+        if fields.len() != 4 {
+            return Err(ParseRectError::WrongFieldCount(fields.len()));
+        }
+
+        Ok(Self::new(
+            fields[0].parse()?,
+            fields[1].parse()?,
+            fields[2].parse()?,
+            fields[3].parse()?,
+        ))
+    }
+}
+
+/// Widens the rect to `i64` co-ords. This never fails.
+impl From<Rect> for RectI64 {
+    fn from(val: Rect) -> Self {
+        Self::new(val.left as i64, val.top as i64, val.wid as i64, val.hgt as i64)
+    }
+}
+
+/// Narrows the rect to `i32` co-ords, failing if any field, [`RectI64::right`] or
+/// [`RectI64::bottom`] does not fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use rect::{Rect, RectI64};
+///
+/// assert_eq!(Rect::try_from(RectI64::new(1, 5, 3, 4)), Ok(Rect::new(1, 5, 3, 4)));
+/// assert!(Rect::try_from(RectI64::new(i32::MAX as i64, 5, 2, 4)).is_err());
+/// ```
+impl TryFrom<RectI64> for Rect {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(val: RectI64) -> Result<Self, Self::Error> {
+        i32::try_from(val.right())?;
+        i32::try_from(val.bottom())?;
+
+        Ok(Self::new(
+            i32::try_from(val.left)?,
+            i32::try_from(val.top)?,
+            i32::try_from(val.wid)?,
+            i32::try_from(val.hgt)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unittests {
+    use super::*;
+
+    #[test]
+    fn const_rect() {
+        const ROOM: Rect = Rect::new(0, 0, 10, 8);
+        const AREA: u32 = ROOM.area();
+        const BOTTOM_RIGHT: (i32, i32) = (ROOM.right(), ROOM.bottom());
+        const INNER: Rect = Rect::new(1, -1, 8, 6);
+
+        const { assert!(ROOM.contains_rect(&INNER) && !INNER.is_empty()) };
+
+        assert_eq!(AREA, 80);
+        assert_eq!(BOTTOM_RIGHT, (9, -7));
+    }
+
+    #[test]
+    fn limits_max() {
+        let test_rect = Rect::new(i32::MAX - 9, i32::MAX, 10, 10);
+
+        assert_eq!(test_rect.right(), i32::MAX);
+        assert_eq!(test_rect.top_right(), Point::new(i32::MAX, i32::MAX));
+        assert!(test_rect.contains(Point::new(i32::MAX, i32::MAX)));
+        assert!(!test_rect.contains(Point::new(i32::MAX - 10, i32::MAX)));
+        assert_eq!(test_rect.area(), 100);
+    }
+
+    #[test]
+    fn limits_contains_overflowing() {
+        let test_rect = Rect::new(i32::MAX - 2, i32::MIN + 2, i32::MAX, i32::MAX);
+
+        assert!(test_rect.contains(Point::new(i32::MAX, i32::MIN)));
+        assert!(test_rect.contains(Point::new(i32::MAX - 2, i32::MIN + 2)));
+        assert!(!test_rect.contains(Point::new(i32::MAX - 3, i32::MIN)));
+        assert!(!test_rect.contains(Point::new(i32::MAX, i32::MIN + 3)));
+        assert!(!test_rect.contains(Point::new(i32::MIN, i32::MIN)));
+    }
+
+    #[test]
+    fn limits_min() {
+        let test_rect = Rect::new(i32::MIN, i32::MIN + 9, 10, 10);
+
+        assert_eq!(test_rect.bottom(), i32::MIN);
+        assert_eq!(test_rect.bottom_left(), Point::new(i32::MIN, i32::MIN));
+        assert!(test_rect.contains(Point::new(i32::MIN, i32::MIN)));
+        assert_eq!(test_rect.cells().len(), 100);
+    }
+
+    #[test]
+    fn checked_new_valid() {
+        assert_eq!(Rect::checked_new(-4, 2, 3, 1), Some(Rect::new(-4, 2, 3, 1)));
+        assert_eq!(
+            Rect::checked_new(i32::MAX - 9, i32::MIN + 9, 10, 10),
+            Some(Rect::new(i32::MAX - 9, i32::MIN + 9, 10, 10))
+        );
+    }
+
+    #[test]
+    fn checked_new_dimensions() {
+        assert_eq!(Rect::checked_new(0, 0, 0, 3), None);
+        assert_eq!(Rect::checked_new(0, 0, 3, 0), None);
+        assert_eq!(Rect::checked_new(0, 0, -2, 3), None);
+        assert_eq!(Rect::checked_new(0, 0, 3, i32::MIN), None);
+    }
+
+    #[test]
+    fn checked_new_overflow() {
+        assert_eq!(Rect::checked_new(i32::MAX - 8, 0, 10, 10), None);
+        assert_eq!(Rect::checked_new(0, i32::MIN + 8, 10, 10), None);
+        assert_eq!(Rect::checked_new(2, 0, i32::MAX, 1), None);
+    }
+
+    #[test]
+    fn i64_beyond_i32() {
+        let test_rect = RectI64::new(i32::MAX as i64 - 4, i32::MIN as i64 + 4, 10, 10);
+
+        assert_eq!(test_rect.right(), i32::MAX as i64 + 5);
+        assert_eq!(test_rect.bottom(), i32::MIN as i64 - 5);
+        assert!(test_rect.contains(i32::MAX as i64 + 1, i32::MIN as i64 - 1));
+        assert!(Rect::try_from(test_rect).is_err());
+        assert_eq!(RectI64::from(Rect::new(-3, 2, 4, 5)), RectI64::new(-3, 2, 4, 5));
+        assert_eq!(Rect::try_from(RectI64::new(-3, 2, 4, 5)), Ok(Rect::new(-3, 2, 4, 5)));
+    }
+
+    #[test]
+    fn i64_limits() {
+        let max = RectI64::new(i64::MAX - 9, i64::MAX, 10, 10);
+        let min = RectI64::new(i64::MIN, i64::MIN + 9, 10, 10);
+
+        assert_eq!((max.right(), max.bottom()), (i64::MAX, i64::MAX - 9));
+        assert!(max.contains(i64::MAX, i64::MAX));
+        assert!(!max.contains(i64::MAX - 10, i64::MAX));
+        assert_eq!((min.left, min.bottom()), (i64::MIN, i64::MIN));
+        assert!(min.contains(i64::MIN, i64::MIN));
+        assert_eq!(max.area(), 100);
+        assert!(!max.overlaps(&min));
+        assert_eq!(
+            max.union(&max.translated(-5, 0)),
+            RectI64::new(i64::MAX - 14, i64::MAX, 15, 10)
+        );
+        assert_eq!(RectI64::checked_new(i64::MAX - 8, 0, 10, 10), None);
+    }
+
+    #[test]
+    fn i64_cells_at_limits() {
+        let test_rect = RectI64::new(i64::MAX - 2, i64::MIN + 1, 3, 2);
+        let cells: Vec<(i64, i64)> = test_rect.cells().collect();
+        let edges: Vec<(i64, i64)> = test_rect.edges().collect();
+
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], test_rect.top_left());
+        assert_eq!(cells[5], (i64::MAX, i64::MIN));
+        assert_eq!(edges.len(), 6);
+        assert!(cells.iter().all(|pos| edges.contains(pos)));
+        assert_eq!(RectI64::EMPTY.cells().count(), 0);
+        assert_eq!(RectI64::EMPTY.edges().count(), 0);
+    }
+
+    #[test]
+    fn i64_edges_match_rect() {
+        let rects = [Rect::new(1, 1, 3, 3), Rect::new(-2, 4, 1, 5), Rect::new(0, 0, 4, 1), Rect::new(3, 3, 1, 1)];
+        let widen = |pos: Point| (pos.x as i64, pos.y as i64);
+
+        for test_rect in rects {
+            let wide = RectI64::from(test_rect);
+            let edges: Vec<(i64, i64)> = test_rect.edges().map(widen).collect();
+            let cells: Vec<(i64, i64)> = test_rect.cells().map(widen).collect();
+
+            assert_eq!(wide.edges().collect::<Vec<_>>(), edges);
+            assert_eq!(wide.cells().collect::<Vec<_>>(), cells);
+        }
+    }
+
+    #[test]
+    fn i64_centre_beyond_i32() {
+        let container = RectI64::new(i64::MAX - 99, i64::MAX, 100, 100);
+        let mut test_rect = RectI64::new(0, 0, 10, 4).align_to(&container, Alignment::Center);
+
+        assert!(container.contains_rect(&test_rect));
+        assert_eq!(test_rect.center(), container.center());
+
+        test_rect.centre_on(-5_000_000_000, 0);
+
+        assert_eq!(test_rect.center(), (-5_000_000_000, 0));
+        assert_eq!(test_rect.expanded(-3, 2).expanded(3, -2).wid, 16);
+        assert_eq!("-5000000005, 2, 10, 4".parse::<RectI64>(), Ok(test_rect));
+    }
+
+    #[test]
+    fn cells_test() {
+        let test_rect = Rect::new(1, 2, 3, 4);
+        let mut expected = Vec::new();
+
+        for y in (test_rect.bottom()..=test_rect.top).rev() {
+            for x in test_rect.left..=test_rect.right() {
+                expected.push(Point::new(x, y));
+            }
+        }
+
+        assert_eq!(expected, test_rect.cells().collect::<Vec<Point>>());
+    }
+
+    #[test]
+    fn from_corners_any_direction() {
+        let top_left = Point::new(-2, 4);
+        let top_right = Point::new(3, 4);
+        let bottom_left = Point::new(-2, 1);
+        let bottom_right = Point::new(3, 1);
+        let expected = Rect::new(-2, 4, 6, 4);
+
+        assert_eq!(Rect::from_corners(top_left, bottom_right), expected);
+        assert_eq!(Rect::from_corners(bottom_right, top_left), expected);
+        assert_eq!(Rect::from_corners(top_right, bottom_left), expected);
+        assert_eq!(Rect::from_corners(bottom_left, top_right), expected);
+    }
+
+    #[test]
+    fn grow_to_include_scatter() {
+        let points = [Point::new(3, 3), Point::new(-1, 5), Point::new(7, -2), Point::new(0, 0), Point::new(2, 4)];
+        let mut test_rect = Rect::new(3, 3, 1, 1);
+
+        for pos in points {
+            test_rect.grow_to_include(pos);
+            assert!(test_rect.contains(pos));
+        }
+
+        assert_eq!(test_rect, Rect::new(-1, 5, 9, 8));
+        assert!(points.iter().all(|&pos| test_rect.contains(pos)));
+
+        test_rect.grow_to_include(Point::new(1, 1));
+        assert_eq!(test_rect, Rect::new(-1, 5, 9, 8));
+    }
+
+    #[test]
+    fn bounding_box_scatter() {
+        let points = [Point::new(3, 3), Point::new(-1, 5), Point::new(7, -2), Point::new(0, 0), Point::new(2, 4)];
+        let bounds = Rect::bounding_box(points).unwrap();
+
+        assert_eq!(bounds, Rect::new(-1, 5, 9, 8));
+        assert_eq!(Rect::bounding_box([Point::new(2, 2)]), Some(Rect::new(2, 2, 1, 1)));
+        assert_eq!(Rect::bounding_box(Vec::new()), None);
+    }
+
+    #[test]
+    fn cells_len() {
+        let test_rect = Rect::new(1, 2, 3, 4);
+        let mut cells = test_rect.cells();
+
+        assert_eq!(cells.len() as u32, test_rect.area());
+
+        for remaining in (0..test_rect.area() as usize).rev() {
+            cells.next();
+            assert_eq!(cells.len(), remaining);
+            assert_eq!(cells.size_hint(), (remaining, Some(remaining)));
+        }
+
+        assert_eq!(cells.next(), None);
+        assert_eq!(Rect::new(0, 0, 0, 4).cells().len(), 0);
+        assert_eq!(Rect::new(0, 0, 0, 4).cells().next(), None);
+    }
+
+    #[test]
+    fn cells_fused() {
+        let mut cells = Rect::new(0, 0, 2, 2).cells();
+
+        assert_eq!(cells.by_ref().count(), 4);
+
+        for _ in 0..5 {
+            assert_eq!(cells.next(), None);
+        }
+    }
+
+    #[test]
+    fn into_iter_ref() {
+        let test_rect = Rect::new(-1, 3, 3, 2);
+        let mut visited = Vec::new();
+
+        for pos in &test_rect {
+            visited.push(pos);
+        }
+
+        assert_eq!(visited, test_rect.cells().collect::<Vec<Point>>());
+    }
+	
+	#[test]
+    fn inner_cells_test1() {
+        let test_rect = Rect::new(1, 3, 3, 3);
+        let expected = vec![Point::new(2, 2)];
+
+        assert_eq!(expected, test_rect.inner_cells().collect::<Vec<Point>>());
+    }
+	
+	#[test]
+    fn inner_cells_test2() {
+        let test_rect = Rect::new(1, 3, 4, 4);
+        let expected = vec![Point::new(2, 2), Point::new(3, 2), Point::new(2, 1), Point::new(3, 1)];
+		
+		for cl in test_rect.inner_cells() {
+			assert!(expected.contains(&cl), "{cl} not expected");
+		}
+    }
+
+    #[test]
+    fn inner_cells_thin() {
+        assert_eq!(Rect::new(0, 0, 1, 5).inner_cells().count(), 0);
+        assert_eq!(Rect::new(0, 0, 2, 5).inner_cells().count(), 0);
+        assert_eq!(Rect::new(0, 0, 5, 2).inner_cells().count(), 0);
+
+        let column = Rect::new(0, 0, 3, 5).inner_cells().collect::<Vec<Point>>();
+
+        assert_eq!(column, vec![Point::new(1, -1), Point::new(1, -2), Point::new(1, -3)]);
+    }
+
+    #[test]
+    fn intersection_touching_edges() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+        let right_of = Rect::new(3, 0, 3, 3);
+        let diagonal = Rect::new(3, -3, 3, 3);
+
+        assert_eq!(test_rect.intersection(&right_of), None);
+        assert_eq!(test_rect.intersection(&diagonal), None);
+    }
+
+    #[test]
+    fn intersection_shared_corner() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+        let other = Rect::new(2, -2, 3, 3);
+
+        assert_eq!(test_rect.intersection(&other), Some(Rect::new(2, -2, 1, 1)));
+        assert!(test_rect.overlaps(&other));
+    }
+
+    #[test]
+    fn edges_unique() {
+        for test_rect in [Rect::new(0, 0, 4, 3), Rect::new(-2, 5, 2, 2), Rect::new(3, 1, 7, 5)] {
+            let edges = test_rect.edges().collect::<Vec<Point>>();
+
+            for (i, pos) in edges.iter().enumerate() {
+                assert!(!edges[i + 1..].contains(pos), "{pos} repeated");
+            }
+
+            assert_eq!(edges.len() as u32, test_rect.perimeter());
+        }
+    }
+
+    #[test]
+    fn edges_len() {
+        let mut edges = Rect::new(0, 0, 4, 3).edges();
+
+        assert_eq!(edges.len(), 10);
+        edges.nth(3);
+        assert_eq!(edges.len(), 6);
+        assert_eq!(edges.by_ref().count(), 6);
+        assert_eq!(edges.next(), None);
+    }
+
+    #[test]
+    fn corners_order() {
+        let test_rect = Rect::new(-4, 2, 6, 3);
+
+        assert_eq!(
+            test_rect.corners(),
+            [test_rect.top_left(), test_rect.top_right(), test_rect.bottom_left(), test_rect.bottom_right()]
+        );
+    }
+
+    #[test]
+    fn edges_adjacent() {
+        for test_rect in [Rect::new(0, 0, 4, 3), Rect::new(-2, 5, 2, 2), Rect::new(3, 1, 1, 4), Rect::new(3, 1, 5, 1)] {
+            let edges = test_rect.edges().collect::<Vec<Point>>();
+
+            assert_eq!(edges[0], test_rect.top_left());
+
+            for pair in edges.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+
+                assert_eq!((a.x - b.x).abs() + (a.y - b.y).abs(), 1, "{a} and {b} not adjacent");
+            }
+        }
+
+        // Going right first, then down, makes the walk clockwise.
+        assert_eq!(Rect::new(0, 0, 3, 3).edges().nth(3), Some(Point::new(2, -1)));
+    }
+
+    #[test]
+    fn nearest_corner_clear() {
+        let test_rect = Rect::new(-3, 3, 7, 5);
+
+        assert_eq!(test_rect.nearest_corner_kind(Point::new(-2, 0)), Corner::BottomLeft);
+        assert_eq!(test_rect.nearest_corner(Point::new(-2, 0)), Point::new(-3, -1));
+        assert_eq!(test_rect.nearest_corner_kind(Point::new(10, 10)), Corner::TopRight);
+        assert_eq!(test_rect.nearest_corner(Point::new(10, 10)), test_rect.top_right());
+    }
+
+    #[test]
+    fn nearest_corner_tie() {
+        let test_rect = Rect::new(-3, 3, 7, 5);
+
+        assert_eq!(test_rect.nearest_corner_kind(test_rect.center()), Corner::TopLeft);
+        // Equally near both right corners.
+        assert_eq!(test_rect.nearest_corner_kind(Point::new(9, 1)), Corner::TopRight);
+    }
+
+    #[test]
+    fn edges_line() {
+        let horizontal = Rect::new(0, 0, 4, 1);
+        let vertical = Rect::new(0, 0, 1, 3);
+
+        assert_eq!(horizontal.edges().collect::<Vec<Point>>(), horizontal.cells().collect::<Vec<Point>>());
+        assert_eq!(vertical.edges().collect::<Vec<Point>>(), vertical.cells().collect::<Vec<Point>>());
+    }
+
+    #[test]
+    fn edges_single_tile() {
+        let test_rect = Rect::new(2, 2, 1, 1);
+
+        assert_eq!(test_rect.edges().collect::<Vec<Point>>(), vec![Point::new(2, 2)]);
+        assert_eq!(Rect::new(0, 0, 0, 3).edges().next(), None);
+    }
+
+    #[test]
+    fn display_test() {
+        let test_rect = Rect::new(-2, 7, 10, 1);
+
+        assert_eq!(format!("{test_rect}"), "Rect(left=-2, top=7, 10x1)");
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(" -1,2 ,3, 4 ".parse::<Rect>(), Ok(Rect::new(-1, 2, 3, 4)));
+    }
+
+    #[test]
+    fn parse_wrong_field_count() {
+        assert_eq!("1,2,3".parse::<Rect>(), Err(ParseRectError::WrongFieldCount(3)));
+        assert_eq!("1,2,3,4,5".parse::<Rect>(), Err(ParseRectError::WrongFieldCount(5)));
+        assert_eq!("".parse::<Rect>(), Err(ParseRectError::WrongFieldCount(1)));
+    }
+
+    #[test]
+    fn parse_invalid_int() {
+        assert!(matches!("a,b,c,d".parse::<Rect>(), Err(ParseRectError::InvalidInt(_))));
+        assert!(matches!("1,2,3,".parse::<Rect>(), Err(ParseRectError::InvalidInt(_))));
+    }
+
+    #[test]
+    fn try_from_valid() {
+        assert_eq!(Rect::try_from((-3, 2, 1, 1)), Ok(Rect::new(-3, 2, 1, 1)));
+    }
+
+    #[test]
+    fn try_from_invalid() {
+        assert_eq!(Rect::try_from((0, 0, -2, 3)), Err(DimensionError::Width(-2)));
+        assert_eq!(Rect::try_from((0, 0, 2, 0)), Err(DimensionError::Height(0)));
+        assert_eq!(Rect::try_from((0, 0, 0, 0)), Err(DimensionError::Width(0)));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_round_trip() {
+        use glam::IVec2;
+
+        for test_rect in [Rect::new(1, 5, 3, 4), Rect::new(-6, -2, 1, 9)] {
+            let (min, size): (IVec2, IVec2) = test_rect.into();
+            let (min_tile, max_tile) = test_rect.to_glam_min_max();
+
+            assert_eq!(Rect::from((min, size)), test_rect);
+            assert_eq!(min, min_tile);
+            assert_eq!(max_tile - min_tile + IVec2::ONE, size);
+        }
+    }
+
+    #[test]
+    fn align_center() {
+        let container = Rect::new(-3, 4, 8, 5);
+        let test_rect = Rect::new(0, 0, 3, 2);
+        let mut centred = test_rect;
+        centred.centre_on(container.center());
+
+        assert_eq!(test_rect.align_to(&container, Alignment::Center), centred);
+        assert_eq!(centred, Rect::new(0, 3, 3, 2));
+    }
+
+    #[test]
+    fn align_corners() {
+        let container = Rect::new(-3, 4, 8, 5);
+        let test_rect = Rect::new(0, 0, 3, 2);
+        let top_left = test_rect.align_to(&container, Alignment::TopLeft);
+        let bottom_right = test_rect.align_to(&container, Alignment::BottomRight);
+
+        assert_eq!(top_left.top_left(), container.top_left());
+        assert_eq!(bottom_right.bottom_right(), container.bottom_right());
+        assert!(container.contains_rect(&top_left));
+        assert!(container.contains_rect(&bottom_right));
+    }
+
+    #[test]
+    fn sort_by_area() {
+        let mut rects = [
+            Rect::new(0, 0, 3, 1),
+            Rect::new(4, 2, 0, 7),
+            Rect::new(-2, 5, i32::MAX, 3),
+            Rect::new(1, 1, 4, 4),
+            Rect::new(0, 0, i32::MAX, 2),
+            Rect::new(9, 9, 2, 2),
+        ];
+        rects.sort_by(|a, b| Rect::cmp_area(b, a));
+
+        assert_eq!(rects[0], Rect::new(-2, 5, i32::MAX, 3));
+        assert_eq!(rects[1], Rect::new(0, 0, i32::MAX, 2));
+        assert_eq!(rects[5], Rect::new(4, 2, 0, 7));
+        assert!(rects[2..].windows(2).all(|pair| pair[0].area() >= pair[1].area()));
+    }
+
+    #[test]
+    fn contains_all_any() {
+        let test_rect = Rect::new(-2, 3, 4, 4);
+        let inside = [Point::new(-2, 3), Point::new(1, 0), Point::new(0, 1)];
+        let outside = [Point::new(-3, 3), Point::new(2, 0), Point::new(0, -1)];
+        let mixed = [inside[0], outside[1], inside[2]];
+
+        assert!(test_rect.contains_all(&inside));
+        assert!(!test_rect.contains_all(&outside));
+        assert!(!test_rect.contains_all(&mixed));
+        assert!(test_rect.contains_all(&[]));
+
+        assert!(test_rect.contains_any(&inside));
+        assert!(!test_rect.contains_any(&outside));
+        assert!(test_rect.contains_any(&mixed));
+        assert!(!test_rect.contains_any(&[]));
+    }
+
+    #[test]
+    fn clip_test() {
+        let test_rect = Rect::new(-3, 4, 7, 6);
+        let bounds = Rect::new(1, 6, 5, 4);
+        let filtered: Vec<_> = test_rect.cells().filter(|&pos| bounds.contains(pos)).collect();
+
+        assert_eq!(test_rect.clip(&bounds).collect::<Vec<_>>(), filtered);
+        assert_eq!(test_rect.clip(&bounds).len(), 6);
+        assert_eq!(test_rect.clip(&Rect::new(10, 4, 2, 2)).len(), 0);
+    }
+
+    #[test]
+    fn centre_on_bias() {
+        let centre = Point::new(3, -1);
+        let mut floor = Rect::new(0, 0, 4, 3);
+        let mut round = floor;
+        floor.centre_on(centre);
+        round.centre_on_round(centre);
+
+        // Columns 1 to 4 put the extra column left of the centre, 2 to 5 right of it.
+        assert_eq!(floor, Rect::new(1, 0, 4, 3));
+        assert_eq!(round, Rect::new(2, 0, 4, 3));
+        assert_eq!(floor.center(), centre);
+        assert_eq!(Rect::new(0, 0, 4, 3).centering_offset(centre), Point::new(1, 0));
+    }
+
+    #[test]
+    fn centre_on_round_odd() {
+        let centre = Point::new(-2, 5);
+        let mut floor = Rect::new(0, 0, 5, 3);
+        let mut round = floor;
+        floor.centre_on(centre);
+        round.centre_on_round(centre);
+
+        assert_eq!(floor, round);
+    }
+
+    #[test]
+    fn centre_on_round_even() {
+        let mut test_rect = Rect::new(0, 0, 4, 6);
+
+        for centre in [Point::new(5, -3), Point::new(-5, 3), Point::new(0, 0)] {
+            test_rect.centre_on_round(centre);
+            let (x, y) = test_rect.center_f64();
+
+            assert_eq!((x - centre.x as f64).abs(), 0.5);
+            assert_eq!((y - centre.y as f64).abs(), 0.5);
+        }
+
+        let mut positive = test_rect;
+        let mut negative = test_rect;
+        positive.centre_on_round(Point::new(5, -3));
+        negative.centre_on_round(Point::new(-5, 3));
+
+        assert_eq!(negative.left, -positive.right());
+        assert_eq!(negative.top, -positive.bottom());
+        assert_eq!(test_rect.center_f64(), (0.5, 0.5));
+    }
+
+    #[test]
+    fn center_f64_even() {
+        let test_rect = Rect::new(-3, 2, 6, 3);
+
+        assert_eq!(test_rect.center_f64(), (-0.5, 1.0));
+        assert_eq!(test_rect.center(), Point::new(0, 1));
+    }
+
+    #[test]
+    fn quadrants_even() {
+        let test_rect = Rect::new(-2, 3, 4, 6);
+
+        assert_eq!(
+            test_rect.quadrants(),
+            [Rect::new(-2, 3, 2, 3), Rect::new(0, 3, 2, 3), Rect::new(-2, 0, 2, 3), Rect::new(0, 0, 2, 3)]
+        );
+    }
+
+    #[test]
+    fn quadrants_odd() {
+        let test_rect = Rect::new(1, 1, 7, 3);
+        let quadrants = test_rect.quadrants();
+
+        assert_eq!(quadrants.to_vec(), test_rect.subdivide(2, 2));
+        assert_eq!(quadrants.iter().map(Rect::area).sum::<u32>(), test_rect.area());
+        assert_eq!(quadrants[0].union(&quadrants[3]), test_rect);
+
+        for (i, quadrant) in quadrants.iter().enumerate() {
+            assert!(quadrants[i + 1..].iter().all(|other| !quadrant.overlaps(other)));
+        }
+    }
+
+    #[test]
+    fn map_corners_rotate() {
+        let test_rect = Rect::new(1, 5, 4, 2);
+        let rotated = test_rect.map_corners(|mut pos| {
+            pos.rotate_90_cw_ip();
+            pos
+        });
+
+        assert_eq!(rotated, Rect::new(4, -1, 2, 4));
+        assert_eq!(test_rect.map_position(|pos| pos + Point::new(2, 0)), Rect::new(3, 5, 4, 2));
+    }
+
+    #[test]
+    fn snap_to_grid_aligned() {
+        let test_rect = Rect::new(-8, 15, 16, 24);
+
+        assert_eq!(test_rect.snap_to_grid(8), test_rect);
+        assert_eq!(test_rect.snap_to_grid(1), test_rect);
+    }
+
+    #[test]
+    fn snap_to_grid_all_fields() {
+        let test_rect = Rect::new(-5, 3, 7, 9);
+        let snapped = test_rect.snap_to_grid(8);
+
+        assert_eq!(snapped, Rect::new(-8, 7, 16, 16));
+        assert!(snapped.contains_rect(&test_rect));
+        assert_eq!((snapped.left % 8, snapped.bottom() % 8), (0, 0));
+        assert_eq!((snapped.wid % 8, snapped.hgt % 8), (0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn snap_to_grid_zero() {
+        Rect::new(0, 0, 3, 3).snap_to_grid(0);
+    }
+
+    #[test]
+    fn resize_center() {
+        for (wid, hgt) in [(7, 3), (2, 8), (5, 5)] {
+            let mut test_rect = Rect::new(-1, 2, 5, 5);
+            let centre = test_rect.center();
+            test_rect.resize(wid, hgt, Alignment::Center);
+
+            assert_eq!((test_rect.wid, test_rect.hgt), (wid, hgt));
+            assert_eq!(test_rect.center(), centre);
+        }
+    }
+
+    #[test]
+    fn resize_bottom_right() {
+        let mut test_rect = Rect::new(-1, 2, 5, 5);
+        let corner = test_rect.bottom_right();
+        test_rect.resize(2, 9, Alignment::BottomRight);
+
+        assert_eq!(test_rect, Rect::new(2, 6, 2, 9));
+        assert_eq!(test_rect.bottom_right(), corner);
+    }
+
+    #[test]
+    fn empty_const() {
+        let mut test_rect = Rect::EMPTY;
+
+        assert_eq!(Rect::EMPTY.area(), 0);
+        assert!(Rect::EMPTY.is_empty());
+        assert_eq!(Rect::UNIT.cells().collect::<Vec<_>>(), vec![Point::new(0, 0)]);
+
+        test_rect.grow_to_include(Point::new(4, -2));
+
+        assert_eq!(test_rect, Rect::new(4, -2, 1, 1));
+    }
+
+    #[test]
+    fn line_through() {
+        let test_rect = Rect::new(-2, 2, 5, 5);
+        let (a, b) = (Point::new(-6, 0), Point::new(6, 0));
+
+        assert!(test_rect.intersects_line(a, b));
+        assert!(test_rect.intersects_line(b, a));
+        assert_eq!(test_rect.clip_line(a, b), Some((Point::new(-2, 0), Point::new(2, 0))));
+        assert_eq!(test_rect.clip_line(b, a), Some((Point::new(2, 0), Point::new(-2, 0))));
+    }
+
+    #[test]
+    fn line_outside() {
+        let test_rect = Rect::new(-2, 2, 5, 5);
+
+        assert!(!test_rect.intersects_line(Point::new(-6, 3), Point::new(6, 3)));
+        assert!(!test_rect.intersects_line(Point::new(3, 5), Point::new(6, -2)));
+        // Passes the top right corner diagonally without touching it.
+        assert!(!test_rect.intersects_line(Point::new(1, 4), Point::new(4, 1)));
+        assert!(!Rect::new(0, 0, 0, 0).intersects_line(Point::new(-1, 0), Point::new(1, 0)));
+    }
+
+    #[test]
+    fn line_endpoint_inside() {
+        let test_rect = Rect::new(-2, 2, 5, 5);
+        let inside = Point::new(1, -1);
+
+        assert!(test_rect.intersects_line(inside, Point::new(1, -10)));
+        assert_eq!(test_rect.clip_line(inside, Point::new(1, -10)), Some((inside, Point::new(1, -2))));
+        assert_eq!(test_rect.clip_line(inside, inside), Some((inside, inside)));
+        assert_eq!(test_rect.clip_line(Point::new(9, 9), Point::new(9, 9)), None);
+    }
+
+    #[test]
+    fn row_cells_in_range() {
+        let test_rect = Rect::new(-2, 1, 4, 3);
+
+        assert_eq!(
+            test_rect.row_cells(-1).collect::<Vec<_>>(),
+            (-2..2).map(|x| Point::new(x, -1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            test_rect.column_cells(1).collect::<Vec<_>>(),
+            vec![Point::new(1, 1), Point::new(1, 0), Point::new(1, -1)]
+        );
+    }
+
+    #[test]
+    fn row_cells_out_of_range() {
+        let test_rect = Rect::new(-2, 1, 4, 3);
+
+        assert_eq!(test_rect.row_cells(2).len(), 0);
+        assert_eq!(test_rect.row_cells(-2).len(), 0);
+        assert_eq!(test_rect.column_cells(2).len(), 0);
+        assert_eq!(test_rect.column_cells(-3).len(), 0);
+    }
+
+    #[test]
+    fn row_cells_single_tile() {
+        let test_rect = Rect::new(5, 5, 1, 1);
+
+        assert_eq!(test_rect.row_cells(5).collect::<Vec<_>>(), vec![Point::new(5, 5)]);
+        assert_eq!(test_rect.column_cells(5).collect::<Vec<_>>(), vec![Point::new(5, 5)]);
+        assert_eq!(test_rect.row_cells(4).next(), None);
+    }
+
+    #[test]
+    fn same_size_moved() {
+        let test_rect = Rect::new(2, -3, 4, 7);
+
+        assert!(test_rect.same_size(&test_rect.translated(Point::new(-10, 6))));
+        assert!(test_rect.same_shape_normalized(&Rect::new(0, 0, -4, -7)));
+    }
+
+    #[test]
+    fn same_size_different() {
+        let test_rect = Rect::new(2, -3, 4, 7);
+
+        assert!(!test_rect.same_size(&Rect::new(2, -3, 4, 6)));
+        assert!(!test_rect.same_size(&Rect::new(2, -3, 7, 4)));
+        assert!(!test_rect.same_shape_normalized(&Rect::new(2, -3, -7, 4)));
+    }
+
+    #[test]
+    fn expand_each_asymmetric() {
+        let mut test_rect = Rect::new(0, 0, 4, 4);
+        let (old_right, old_bottom) = (test_rect.right(), test_rect.bottom());
+        test_rect.expand_each(1, 2, 3, 4);
+
+        assert_eq!(test_rect, Rect::new(-1, 2, 8, 10));
+        assert_eq!(test_rect.right(), old_right + 3);
+        assert_eq!(test_rect.bottom(), old_bottom - 4);
+
+        test_rect.expand_each(-1, -2, -3, -4);
+
+        assert_eq!(test_rect, Rect::new(0, 0, 4, 4));
+    }
+
+    #[test]
+    fn checked_area_small() {
+        for test_rect in [Rect::new(0, 0, 3, 4), Rect::new(-7, 2, 1, 1), Rect::new(5, 5, 0, 9)] {
+            assert_eq!(test_rect.checked_area(), Some(test_rect.area() as u64));
+        }
+
+        assert_eq!(Rect::new(0, 0, 3, -4).checked_area(), None);
+    }
+
+    #[test]
+    fn checked_area_large() {
+        let test_rect = Rect::new(i32::MIN, i32::MAX, i32::MAX, i32::MAX);
+
+        assert_eq!(test_rect.checked_area(), Some(4_611_686_014_132_420_609));
+        assert_eq!(test_rect.area(), u32::MAX);
+    }
+
+    #[test]
+    fn lerp_ends() {
+        let from = Rect::new(-7, 3, 1, 9);
+        let to = Rect::new(i32::MAX - 4, i32::MIN + 4, 5, 5);
+
+        assert_eq!(from.lerp(&to, 0.0), from);
+        assert_eq!(from.lerp(&to, 1.0), to);
+        assert_eq!(to.lerp(&from, 1.0), from);
+    }
+
+    #[test]
+    fn lerp_half() {
+        let from = Rect::new(-7, 3, 1, 9);
+        let to = Rect::new(2, -4, 4, 2);
+
+        assert_eq!(from.lerp(&to, 0.5), Rect::new(-3, -1, 3, 6));
+        assert_eq!(from.lerp(&from, 0.5), from);
+    }
+
+    #[test]
+    fn split_evenly_divides() {
+        let test_rect = Rect::new(-1, 5, 6, 9);
+
+        assert_eq!(
+            test_rect.split_evenly_horizontal(3),
+            vec![Rect::new(-1, 5, 6, 3), Rect::new(-1, 2, 6, 3), Rect::new(-1, -1, 6, 3)]
+        );
+        assert_eq!(
+            test_rect.split_evenly_vertical(3),
+            vec![Rect::new(-1, 5, 2, 9), Rect::new(1, 5, 2, 9), Rect::new(3, 5, 2, 9)]
+        );
+    }
+
+    #[test]
+    fn split_evenly_remainder() {
+        let test_rect = Rect::new(-1, 5, 5, 8);
+
+        assert_eq!(
+            test_rect.split_evenly_horizontal(3),
+            vec![Rect::new(-1, 5, 5, 3), Rect::new(-1, 2, 5, 3), Rect::new(-1, -1, 5, 2)]
+        );
+        assert_eq!(
+            test_rect.split_evenly_vertical(3),
+            vec![Rect::new(-1, 5, 2, 8), Rect::new(1, 5, 2, 8), Rect::new(3, 5, 1, 8)]
+        );
+        assert!(test_rect.split_evenly_vertical(0).is_empty());
+    }
+
+    #[test]
+    fn bounding_box_of_scattered() {
+        let rects = [Rect::new(-6, 2, 2, 3), Rect::new(4, 8, 1, 1), Rect::new(0, -5, 3, 2), Rect::new(20, 20, 0, 4)];
+        let bounds = Rect::bounding_box_of(&rects).unwrap();
+
+        assert_eq!(bounds, Rect::new(-6, 8, 11, 15));
+        assert!(rects[..3].iter().all(|rect| bounds.contains_rect(rect)));
+        // Each edge of the bounds is reached by one of the rects.
+        assert_eq!(bounds.left, rects[0].left);
+        assert_eq!(bounds.top, rects[1].top);
+        assert_eq!(bounds.right(), rects[1].right());
+        assert_eq!(bounds.bottom(), rects[2].bottom());
+        assert_eq!(Rect::bounding_box_of(&rects[3..]), None);
+    }
+
+    #[test]
+    fn merge_horizontal() {
+        let test_rect = Rect::new(-2, 3, 2, 4);
+        let other = Rect::new(0, 3, 5, 4);
+
+        assert_eq!(test_rect.merge_if_aligned(&other), Some(Rect::new(-2, 3, 7, 4)));
+        assert_eq!(other.merge_if_aligned(&test_rect), Some(Rect::new(-2, 3, 7, 4)));
+    }
+
+    #[test]
+    fn merge_vertical() {
+        let test_rect = Rect::new(-2, 3, 2, 4);
+        let other = Rect::new(-2, -1, 2, 1);
+
+        assert_eq!(test_rect.merge_if_aligned(&other), Some(Rect::new(-2, 3, 2, 5)));
+        assert_eq!(other.merge_if_aligned(&test_rect), Some(Rect::new(-2, 3, 2, 5)));
+    }
+
+    #[test]
+    fn merge_misaligned() {
+        let test_rect = Rect::new(-2, 3, 2, 4);
+
+        // Side by side, but one tile shorter.
+        assert_eq!(test_rect.merge_if_aligned(&Rect::new(0, 3, 5, 3)), None);
+        // Same rows, but with a gap between them.
+        assert_eq!(test_rect.merge_if_aligned(&Rect::new(1, 3, 5, 4)), None);
+        // Overlapping rather than adjacent.
+        assert_eq!(test_rect.merge_if_aligned(&Rect::new(-1, 3, 5, 4)), None);
+    }
+
+    #[test]
+    fn side_tiles_rows() {
+        let test_rect = Rect::new(-2, 3, 5, 3);
+        let top = test_rect.side_tiles(Side::Top);
+        let bottom = test_rect.side_tiles(Side::Bottom);
+
+        assert_eq!(top.len(), 5);
+        assert_eq!((top[0], top[4]), (test_rect.top_left(), test_rect.top_right()));
+        assert_eq!(bottom.len(), 5);
+        assert_eq!((bottom[0], bottom[4]), (test_rect.bottom_left(), test_rect.bottom_right()));
+    }
+
+    #[test]
+    fn side_tiles_columns() {
+        let test_rect = Rect::new(-2, 3, 5, 3);
+        let left = test_rect.side_tiles(Side::Left);
+        let right = test_rect.side_tiles(Side::Right);
+
+        assert_eq!(left.len(), 3);
+        assert_eq!((left[0], left[2]), (test_rect.top_left(), test_rect.bottom_left()));
+        assert_eq!(right.len(), 3);
+        assert_eq!((right[0], right[2]), (test_rect.top_right(), test_rect.bottom_right()));
+        assert!(Rect::new(0, 0, 0, 3).side_tiles(Side::Left).is_empty());
+    }
+
+    #[test]
+    fn scale_f64_half() {
+        let test_rect = Rect::new(-3, 5, 6, 7);
+        let scaled = test_rect.scale_f64(0.5);
+
+        assert_eq!((scaled.wid, scaled.hgt), (3, 4));
+        assert_eq!(scaled.center(), test_rect.center());
+        assert!(test_rect.contains_rect(&scaled));
+    }
+
+    #[test]
+    fn scale_f64_double() {
+        let test_rect = Rect::new(-3, 5, 6, 7);
+        let scaled = test_rect.scale_f64(2.0);
+
+        assert_eq!(scaled, Rect::new(-6, 9, 12, 14));
+        assert_eq!(scaled.center(), test_rect.center());
+        assert!(test_rect.scale_f64(0.0).is_empty());
+    }
+
+    #[test]
+    fn covers_same_tiles_negative() {
+        let test_rect = Rect::new(-1, 4, 4, 3);
+        let negative = Rect::new(3, 4, -4, 3);
+
+        assert!(test_rect.covers_same_tiles(&negative));
+        assert!(negative.covers_same_tiles(&test_rect));
+        assert_eq!(
+            negative.normalized().cells().collect::<Vec<_>>(),
+            test_rect.cells().collect::<Vec<_>>()
+        );
+        assert!(!test_rect.covers_same_tiles(&Rect::new(4, 4, -4, 3)));
+        assert!(Rect::new(0, 0, 0, 3).covers_same_tiles(&Rect::new(5, 5, 2, 0)));
+    }
+
+    #[test]
+    fn split_at_ratio_even() {
+        let test_rect = Rect::new(-4, 3, 8, 6);
+
+        assert_eq!(
+            test_rect.split_at_ratio_vertical(0.5),
+            (Rect::new(-4, 3, 4, 6), Rect::new(0, 3, 4, 6))
+        );
+        assert_eq!(
+            test_rect.split_at_ratio_horizontal(0.5),
+            (Rect::new(-4, 3, 8, 3), Rect::new(-4, 0, 8, 3))
+        );
+    }
+
+    #[test]
+    fn split_at_ratio_odd() {
+        let test_rect = Rect::new(-4, 3, 7, 7);
+
+        // 30% of 7 is 2.1, so the first part is 2 tiles.
+        assert_eq!(
+            test_rect.split_at_ratio_vertical(0.3),
+            (Rect::new(-4, 3, 2, 7), Rect::new(-2, 3, 5, 7))
+        );
+        assert_eq!(
+            test_rect.split_at_ratio_horizontal(0.3),
+            (Rect::new(-4, 3, 7, 2), Rect::new(-4, 1, 7, 5))
+        );
+        assert_eq!(test_rect.split_at_ratio_vertical(4.0), test_rect.split_at_ratio_vertical(1.0));
+        assert_eq!(test_rect.split_at_ratio_vertical(-1.0).1, test_rect);
+    }
+
+    #[test]
+    fn cells_spiral_permutation() {
+        for test_rect in [Rect::new(-3, 2, 5, 5), Rect::new(4, 4, 6, 3), Rect::new(0, 0, 1, 1)] {
+            let centre = test_rect.center();
+            let spiral = test_rect.cells_spiral().collect::<Vec<_>>();
+            let mut sorted = spiral.clone();
+            let mut cells = test_rect.cells().collect::<Vec<_>>();
+            sorted.sort_by_key(|pos| (pos.x, pos.y));
+            cells.sort_by_key(|pos| (pos.x, pos.y));
+
+            assert_eq!(sorted, cells);
+            assert_eq!(spiral[0], centre);
+
+            let rings = spiral.iter().map(|pos| (pos.x - centre.x).abs().max((pos.y - centre.y).abs()));
+            assert!(rings.clone().zip(rings.skip(1)).all(|(a, b)| a <= b));
+        }
+
+        assert_eq!(Rect::new(0, 0, 0, 4).cells_spiral().next(), None);
+    }
+
+    #[test]
+    fn cells_spiral_clockwise() {
+        let test_rect = Rect::new(0, 1, 5, 3);
+        let spiral = test_rect.cells_spiral().collect::<Vec<_>>();
+
+        // The second ring is cut off above and below, leaving only its sides.
+        let expected = [
+            Point::new(2, 0),
+            Point::new(1, 1),
+            Point::new(2, 1),
+            Point::new(3, 1),
+            Point::new(3, 0),
+            Point::new(3, -1),
+            Point::new(2, -1),
+            Point::new(1, -1),
+            Point::new(1, 0),
+            Point::new(4, 1),
+            Point::new(4, 0),
+            Point::new(4, -1),
+            Point::new(0, -1),
+            Point::new(0, 0),
+            Point::new(0, 1),
+        ];
+
+        assert_eq!(spiral, expected);
+
+        let huge = Rect::new(i32::MIN, i32::MAX, i32::MAX, i32::MAX);
+        assert_eq!(huge.cells_spiral().next(), Some(huge.center()));
+    }
+
+    #[test]
+    fn contains_strict_edges() {
+        let test_rect = Rect::new(-2, 3, 5, 4);
+
+        assert!(!test_rect.contains_strict(Point::new(0, 3)));
+        assert!(!test_rect.contains_strict(Point::new(2, 1)));
+        assert!(!test_rect.contains_strict(Point::new(-2, 3)));
+        assert!(!test_rect.contains_strict(test_rect.bottom_right()));
+        assert!(!test_rect.contains_strict(Point::new(5, 5)));
+    }
+
+    #[test]
+    fn contains_strict_interior() {
+        let test_rect = Rect::new(-2, 3, 5, 4);
+
+        assert!(test_rect.contains_strict(Point::new(0, 1)));
+        assert!(test_rect.inner_cells().all(|pos| test_rect.contains_strict(pos)));
+        assert_eq!(test_rect.cells().filter(|&pos| test_rect.contains_strict(pos)).count(), 6);
+        assert!(!Rect::new(0, 0, 2, 2).cells().any(|pos| Rect::new(0, 0, 2, 2).contains_strict(pos)));
+    }
+
+    #[test]
+    fn snap_no_gap() {
+        let panel = Rect::new(-2, 3, 5, 4);
+        let test_rect = Rect::new(0, 2, 3, 3);
+
+        for snapped in [
+            test_rect.snap_right_of(&panel, 0),
+            test_rect.snap_left_of(&panel, 0),
+            test_rect.snap_above(&panel, 0),
+            test_rect.snap_below(&panel, 0),
+        ] {
+            assert!(snapped.touches(&panel), "{snapped} does not touch {panel}");
+            assert!(!snapped.overlaps(&panel));
+            assert!(snapped.same_size(&test_rect));
+        }
+    }
+
+    #[test]
+    fn snap_gap() {
+        let panel = Rect::new(-2, 3, 5, 4);
+        let test_rect = Rect::new(0, 2, 3, 3);
+
+        assert_eq!(test_rect.snap_right_of(&panel, 2).left, panel.right() + 3);
+        assert_eq!(test_rect.snap_left_of(&panel, 2).right(), panel.left - 3);
+        assert_eq!(test_rect.snap_above(&panel, 2).bottom(), panel.top + 3);
+        assert_eq!(test_rect.snap_below(&panel, 2).top, panel.bottom() - 3);
+        assert!(!test_rect.snap_below(&panel, 2).touches(&panel));
+    }
+
+    #[test]
+    fn fit_wide_in_tall() {
+        let container = Rect::new(-2, 8, 4, 9);
+        let test_rect = Rect::new(0, 0, 8, 2);
+        let fitted = test_rect.fit_within(&container);
+
+        assert_eq!(fitted, Rect::new(-2, 4, 4, 1));
+        assert!(container.contains_rect(&fitted));
+        assert!((fitted.aspect_ratio() - test_rect.aspect_ratio()).abs() < 0.01);
+    }
+
+    #[test]
+    fn fit_tall_in_wide() {
+        let container = Rect::new(-2, 3, 10, 4);
+        let test_rect = Rect::new(0, 0, 6, 9);
+        let fitted = test_rect.fit_within(&container);
+
+        assert_eq!((fitted.wid, fitted.hgt), (3, 4));
+        assert!(container.contains_rect(&fitted));
+        assert!((fitted.aspect_ratio() - test_rect.aspect_ratio()).abs() < 0.1);
+        assert_eq!(Rect::new(5, 5, 2, 2).fit_within(&container), Rect::new(2, 2, 2, 2));
+    }
+
+    #[test]
+    fn fit_extreme_ratio() {
+        let wide = Rect::new(0, 0, 100, 1);
+        let tall = Rect::new(0, 0, 1, 100);
+
+        assert_eq!(wide.fit_within(&Rect::new(0, 8, 4, 9)), Rect::new(0, 4, 4, 1));
+        assert_eq!(tall.fit_within(&Rect::new(0, 3, 9, 4)), Rect::new(4, 3, 1, 4));
+    }
+
+    #[test]
+    fn grow_dims_multiple() {
+        let test_rect = Rect::new(-3, 2, 5, 6);
+
+        assert_eq!(test_rect.grow_dims_to_multiple(4), Rect::new(-3, 2, 8, 8));
+        assert_eq!(test_rect.grow_dims_to_multiple(1), test_rect);
+        assert_eq!(Rect::new(-3, 2, 8, 12).grow_dims_to_multiple(4), Rect::new(-3, 2, 8, 12));
+    }
+
+    #[test]
+    fn grow_dims_pow2() {
+        let test_rect = Rect::new(-3, 2, 5, 6);
+
+        assert_eq!(test_rect.grow_dims_to_pow2(), Rect::new(-3, 2, 8, 8));
+        assert_eq!(Rect::new(0, 0, 1, 16).grow_dims_to_pow2(), Rect::new(0, 0, 1, 16));
+        assert_eq!(Rect::new(0, 0, 0, 17).grow_dims_to_pow2(), Rect::new(0, 0, 0, 32));
+    }
+
+    #[test]
+    fn center_distance_horizontal() {
+        let test_rect = Rect::new(-2, 3, 4, 3);
+        let other = test_rect.translated(Point::new(6, 0));
+
+        assert_eq!(test_rect.center_distance(&other), 6.0);
+        assert_eq!(test_rect.center_manhattan_distance(&other), 6);
+        assert_eq!(other.center_manhattan_distance(&test_rect), 6);
+    }
+
+    #[test]
+    fn center_distance_diagonal() {
+        let test_rect = Rect::new(-2, 3, 4, 3);
+        let other = Rect::new(1, -1, 2, 1);
+
+        // The true centres are at (-0.5, 2) and (1.5, -1).
+        assert_eq!(test_rect.center_distance(&other), 13.0f64.sqrt());
+        assert_eq!(test_rect.center_manhattan_distance(&other), 5);
+    }
+
+    #[test]
+    fn clamp_within_each_edge() {
+        let bounds = Rect::new(-5, 5, 10, 10);
+        let cases = [
+            (Rect::new(-7, 0, 3, 3), Rect::new(-5, 0, 3, 3)),
+            (Rect::new(3, 0, 3, 3), Rect::new(2, 0, 3, 3)),
+            (Rect::new(0, 7, 3, 3), Rect::new(0, 5, 3, 3)),
+            (Rect::new(0, -3, 3, 3), Rect::new(0, -2, 3, 3)),
+            (Rect::new(-1, 1, 3, 3), Rect::new(-1, 1, 3, 3)),
+        ];
+
+        for (mut test_rect, expected) in cases {
+            test_rect.clamp_within(&bounds);
+
+            assert_eq!(test_rect, expected);
+            assert!(bounds.contains_rect(&test_rect));
+        }
+    }
+
+    #[test]
+    fn clamp_within_too_large() {
+        let bounds = Rect::new(-5, 5, 10, 10);
+        let mut test_rect = Rect::new(8, -8, 12, 4);
+        test_rect.clamp_within(&bounds);
+
+        assert_eq!(test_rect, Rect::new(-5, -1, 12, 4));
+
+        let mut test_rect = Rect::new(8, -8, 12, 14);
+        test_rect.clamp_within(&bounds);
+
+        assert_eq!(test_rect, Rect::new(-5, 5, 12, 14));
+    }
+
+    #[test]
+    fn cells_indexed_last() {
+        let test_rect = Rect::new(-3, 2, 4, 5);
+        let cells = test_rect.cells_indexed().collect::<Vec<_>>();
+
+        assert_eq!(cells.len(), 20);
+        assert_eq!(*cells.last().unwrap(), ((3, 4), test_rect.bottom_right()));
+        assert!(cells.iter().enumerate().all(|(i, &((col, row), _))| (row * 4 + col) as usize == i));
+    }
+
+    #[test]
+    fn index_round_trip() {
+        let test_rect = Rect::new(-3, 2, 4, 5);
+
+        for (i, pos) in test_rect.cells().enumerate() {
+            assert_eq!(test_rect.point_at_index(i), Some(pos));
+            assert_eq!(test_rect.index_of(test_rect.point_at_index(i).unwrap()), Some(i));
+        }
+
+        assert_eq!(test_rect.point_at_index(test_rect.area() as usize), None);
+        assert_eq!(test_rect.index_of(Point::new(1, 2)), None);
+        assert_eq!(Rect::new(0, 0, 0, 3).point_at_index(0), None);
+    }
+
+    #[test]
+    fn tighten_to_corner() {
+        let test_rect = Rect::new(-4, 4, 9, 9);
+        let points = [Point::new(4, -3), Point::new(3, -4), Point::new(4, -4)];
+        let tight = test_rect.tighten_to(&points).unwrap();
+
+        assert_eq!(tight, Rect::new(3, -3, 2, 2));
+        assert!(test_rect.contains_rect(&tight));
+    }
+
+    #[test]
+    fn tighten_to_ignores_outside() {
+        let test_rect = Rect::new(-4, 4, 9, 9);
+        let points = [Point::new(-10, 0), Point::new(0, 0), Point::new(1, 2), Point::new(5, 5)];
+
+        assert_eq!(test_rect.tighten_to(&points), Some(Rect::new(0, 2, 2, 3)));
+        assert_eq!(test_rect.tighten_to(&points[3..]), None);
+        assert_eq!(test_rect.tighten_to(&[]), None);
+    }
+
+    #[test]
+    fn mirror_corners() {
+        let test_rect = Rect::new(-3, 2, 6, 4);
+
+        assert_eq!(test_rect.mirror_point(test_rect.top_left()), test_rect.bottom_right());
+        assert_eq!(test_rect.mirror_point(test_rect.bottom_left()), test_rect.top_right());
+        assert!(test_rect.cells().all(|pos| test_rect.mirror_point(test_rect.mirror_point(pos)) == pos));
+    }
+
+    #[test]
+    fn mirror_center() {
+        let odd = Rect::new(-3, 2, 7, 5);
+        let even = Rect::new(-3, 2, 6, 4);
+
+        assert_eq!(odd.mirror_point(odd.center()), odd.center());
+        assert_eq!(even.mirror_point(even.center()), even.center() + Point::new(-1, 1));
+    }
+
+    #[test]
+    fn checkerboard_split() {
+        let test_rect = Rect::new(-1, 1, 3, 3);
+        let (even, odd) = test_rect.checkerboard();
+
+        assert_eq!((even.len(), odd.len()), (5, 4));
+        assert!(even.iter().all(|pos| !odd.contains(pos)));
+        assert!(even.contains(&test_rect.top_left()) && even.contains(&Point::new(0, 0)));
+        assert_eq!(Rect::new(0, 0, 4, 3).checkerboard().0.len(), 6);
+    }
+
+    #[test]
+    fn wrap_point_edges() {
+        let test_rect = Rect::new(-3, 4, 7, 5);
+
+        assert_eq!(test_rect.wrap_point(Point::new(4, 2)), Point::new(-3, 2));
+        assert_eq!(test_rect.wrap_point(Point::new(-4, 2)), Point::new(3, 2));
+        assert_eq!(test_rect.wrap_point(Point::new(0, 5)), Point::new(0, 0));
+        assert_eq!(test_rect.wrap_point(Point::new(0, -1)), Point::new(0, 4));
+    }
+
+    #[test]
+    fn wrap_point_multiples() {
+        let test_rect = Rect::new(-3, 4, 7, 5);
+
+        assert_eq!(test_rect.wrap_point(Point::new(1 + 7 * 30, 2 - 5 * 12)), Point::new(1, 2));
+        assert_eq!(test_rect.wrap_point(Point::new(1 - 7 * 30, 2 + 5 * 12)), Point::new(1, 2));
+        assert_eq!(test_rect.wrap_point(Point::new(i32::MIN, i32::MAX)), Point::new(-2, 2));
+        assert!(test_rect.cells().all(|pos| test_rect.wrap_point(pos) == pos));
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert_eq!(Rect::default(), Rect::new(0, 0, 0, 0));
+        assert_eq!(Rect::default(), Rect::EMPTY);
+        assert_eq!(Rect::default(), Rect::empty());
+        assert!(Rect::default().is_empty());
+    }
+
+    #[test]
+    fn grow_centered_keeps_centre() {
+        for mut test_rect in [Rect::new(-3, 2, 5, 3), Rect::new(-3, 2, 4, 6)] {
+            let centre = test_rect.center();
+            test_rect.grow_centered(2, 2);
+
+            assert_eq!(test_rect.center(), centre);
+            assert_eq!(test_rect.grown_centered(-2, -2).center(), centre);
+        }
+    }
+
+    #[test]
+    fn grown_centered_size() {
+        let test_rect = Rect::new(-3, 2, 4, 6);
+        let grown = test_rect.grown_centered(2, 2);
+
+        assert_eq!(grown, Rect::new(-5, 4, 8, 10));
+        assert_eq!(grown.grown_centered(-2, -2), test_rect);
+    }
+
+    #[test]
+    fn grow_centered_clamped() {
+        let mut test_rect = Rect::new(-3, 2, 4, 6);
+        test_rect.grow_centered(-1, -4);
+
+        assert_eq!(test_rect, Rect::new(-2, -2, 2, 0));
+        assert!(test_rect.is_empty());
+        assert_eq!(test_rect.center(), Point::new(-1, -2));
+    }
+
+    #[test]
+    fn hash_test() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+
+        assert!(set.insert(Rect::new(0, 0, 3, 3)));
+        assert!(set.insert(Rect::new(1, 0, 3, 3)));
+        assert!(set.insert(Rect::new(0, 0, 3, 4)));
+        assert!(!set.insert(Rect::new(0, 0, 3, 3)));
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Rect::new(1, 0, 3, 3)));
+    }
+
+    #[test]
+    fn ord_test() {
+        use std::collections::BTreeSet;
+
+        let set = BTreeSet::from([
+            Rect::new(0, 2, 3, 3),
+            Rect::new(-1, 2, 3, 3),
+            Rect::new(5, -4, 1, 1),
+            Rect::new(-1, 2, 2, 9),
+            Rect::new(-1, 2, 2, 4),
+        ]);
+
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![
+                Rect::new(5, -4, 1, 1),
+                Rect::new(-1, 2, 2, 4),
+                Rect::new(-1, 2, 2, 9),
+                Rect::new(-1, 2, 3, 3),
+                Rect::new(0, 2, 3, 3),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let test_rect = Rect::new(1, 5, 3, 4);
+        let json = serde_json::to_string(&test_rect).unwrap();
+
+        assert_eq!(json, r#"{"top":5,"left":1,"wid":3,"hgt":4}"#);
+        assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), test_rect);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_array_form() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Room {
+            #[serde(with = "crate::serde_array")]
+            bounds: Rect,
+        }
+
+        let test_rect = Rect::new(1, 2, 3, 4);
+        let room = Room { bounds: test_rect };
+        let json = serde_json::to_string(&RectArray(test_rect)).unwrap();
+        let room_json = serde_json::to_string(&room).unwrap();
+
+        assert_eq!(json, "[1,2,3,4]");
+        assert_eq!(serde_json::from_str::<RectArray>(&json).unwrap().0, test_rect);
+        assert_eq!(room_json, r#"{"bounds":[1,2,3,4]}"#);
+        assert_eq!(serde_json::from_str::<Room>(&room_json).unwrap(), room);
+    }
+
+    #[test]
+    fn expand_up() {
+        let mut test_rect = Rect::new(0, 0, 3, 3);
+        test_rect.expand(Point::new(0, 2));
+
+        assert_eq!(test_rect.bottom(), -2);
+        assert_eq!(test_rect.top, 2);
+        assert_eq!(test_rect.hgt, 5);
+    }
+
+    #[test]
+    fn expand_down() {
+        let mut test_rect = Rect::new(0, 0, 3, 3);
+        test_rect.expand(Point::new(0, -2));
+
+        assert_eq!(test_rect.top, 0);
+        assert_eq!(test_rect.bottom(), -4);
+        assert_eq!(test_rect.hgt, 5);
+    }
+
+    #[test]
+    fn expand_right() {
+        let mut test_rect = Rect::new(0, 0, 3, 3);
+        test_rect.expand(Point::new(2, 0));
+
+        assert_eq!(test_rect.left, 0);
+        assert_eq!(test_rect.right(), 4);
+        assert_eq!(test_rect.wid, 5);
+    }
+
+    #[test]
+    fn expand_left() {
+        let mut test_rect = Rect::new(0, 0, 3, 3);
+        test_rect.expand(Point::new(-2, 0));
+
+        assert_eq!(test_rect.right(), 2);
+        assert_eq!(test_rect.left, -2);
+        assert_eq!(test_rect.wid, 5);
+    }
+
+    #[test]
+    fn shrink_undoes_expand() {
+        let original = Rect::new(-3, 4, 5, 2);
+
+        for dir in [Point::new(2, 0), Point::new(-2, 0), Point::new(0, 3), Point::new(0, -3), Point::new(-1, 4)] {
+            let mut test_rect = original;
+            test_rect.expand(dir);
+            test_rect.shrink(dir);
+
+            assert_eq!(test_rect, original);
+        }
+    }
+
+    #[test]
+    fn shrink_clamps() {
+        let mut test_rect = Rect::new(0, 0, 3, 3);
+        test_rect.shrink(Point::new(-5, 5));
+
+        assert_eq!(test_rect, Rect::new(3, -3, 0, 0));
+    }
+
+    #[test]
+    fn inset_zero() {
+        let test_rect = Rect::new(2, 2, 4, 6);
+
+        assert_eq!(test_rect.inset(0), test_rect);
+        assert_eq!(test_rect.outset(0), test_rect);
+    }
+
+    #[test]
+    fn inset_one() {
+        let test_rect = Rect::new(2, 2, 4, 6);
+
+        assert_eq!(test_rect.inset(1), Rect::new(3, 1, 2, 4));
+        assert_eq!(test_rect.outset(1), Rect::new(1, 3, 6, 8));
+        assert_eq!(test_rect.inset(1).outset(1), test_rect);
+    }
+
+    #[test]
+    fn inset_too_large() {
+        let test_rect = Rect::new(2, 2, 4, 6);
+        let collapsed = test_rect.inset(3);
+
+        assert_eq!(collapsed.wid, 0);
+        assert_eq!(collapsed.hgt, 0);
+        assert_eq!(collapsed.area(), 0);
+    }
+
+    #[test]
+    fn clamp_point_edges() {
+        let test_rect = Rect::new(0, 4, 4, 5);
+
+        assert_eq!(test_rect.clamp_point(Point::new(2, 9)), Point::new(2, 4));
+        assert_eq!(test_rect.clamp_point(Point::new(2, -3)), Point::new(2, 0));
+        assert_eq!(test_rect.clamp_point(Point::new(-6, 2)), Point::new(0, 2));
+        assert_eq!(test_rect.clamp_point(Point::new(8, 2)), Point::new(3, 2));
+    }
+
+    #[test]
+    fn clamp_point_diagonal() {
+        let test_rect = Rect::new(0, 4, 4, 5);
+
+        assert_eq!(test_rect.clamp_point(Point::new(-2, 7)), test_rect.top_left());
+        assert_eq!(test_rect.clamp_point(Point::new(5, -1)), test_rect.bottom_right());
+    }
+
+    #[test]
+    fn closest_edge_point_on_edge() {
+        let test_rect = Rect::new(0, 0, 5, 5);
+
+        for pos in test_rect.edges() {
+            assert_eq!(test_rect.closest_edge_point(pos), pos);
+        }
+    }
+
+    #[test]
+    fn closest_edge_point_tie() {
+        let test_rect = Rect::new(0, 0, 5, 5);
+
+        // Equally near the left and top edges.
+        assert_eq!(test_rect.closest_edge_point(Point::new(1, -1)), Point::new(0, -1));
+        // Equally near the top and right edges.
+        assert_eq!(test_rect.closest_edge_point(Point::new(3, -1)), Point::new(3, 0));
+        // Equally near the right and bottom edges.
+        assert_eq!(test_rect.closest_edge_point(Point::new(3, -3)), Point::new(4, -3));
+        // Equally near all four edges.
+        assert_eq!(test_rect.closest_edge_point(Point::new(2, -2)), Point::new(0, -2));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_point_contained() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(12);
+        let test_rect = Rect::new(-3, 2, 4, 7);
+
+        for _ in 0..200 {
+            assert!(test_rect.contains(test_rect.random_point(&mut rng).unwrap()));
+        }
+
+        assert_eq!(Rect::new(0, 0, 3, -1).random_point(&mut rng), None);
+    }
+
+    #[test]
+    fn distance_axis_aligned() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+        let above = Point::new(1, 4);
+
+        assert_eq!(test_rect.manhattan_distance_to(above), 4);
+        assert_eq!(test_rect.distance_to(above), 4.0);
+    }
+
+    #[test]
+    fn distance_diagonal() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+        let off_corner = Point::new(-3, -6);
+
+        assert_eq!(test_rect.manhattan_distance_to(off_corner), 7);
+        assert_eq!(test_rect.distance_to(off_corner), 5.0);
+    }
+
+    #[test]
+    fn rotate_swaps_dimensions() {
+        for test_rect in [Rect::new(-1, 3, 4, 7), Rect::new(2, 2, 5, 2), Rect::new(0, 0, 6, 6)] {
+            let rotated = test_rect.rotate_90_cw();
+
+            let (x, y) = test_rect.center_f64();
+            let (rotated_x, rotated_y) = rotated.center_f64();
+
+            assert_eq!(rotated.wid, test_rect.hgt);
+            assert_eq!(rotated.hgt, test_rect.wid);
+            assert!((rotated_x - x).abs() + (rotated_y - y).abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn rotate_four_times() {
+        for test_rect in [Rect::new(-1, 3, 4, 7), Rect::new(2, 2, 5, 2), Rect::new(0, 0, 6, 6)] {
+            let rotated = test_rect.rotate_90_cw().rotate_90_cw().rotate_90_cw().rotate_90_cw();
+
+            assert_eq!(rotated, test_rect);
+        }
+    }
+
+    #[test]
+    fn rotate_even_and_odd() {
+        for test_rect in [Rect::new(0, 1, 4, 3), Rect::new(-7, 2, 3, 6), Rect::new(5, -3, 1, 2)] {
+            let turned = test_rect.rotate_90_cw().rotate_90_cw().rotate_90_cw().rotate_90_cw();
+
+            assert_ne!(test_rect.rotate_90_cw(), test_rect.rotate_90_ccw());
+            assert_eq!(test_rect.rotate_90_cw().rotate_90_ccw(), test_rect);
+            assert_eq!(test_rect.rotate_90_ccw().rotate_90_cw(), test_rect);
+            assert_eq!(turned, test_rect);
+        }
+    }
+
+    #[test]
+    fn flip_twice() {
+        let test_rect = Rect::new(-2, 5, 4, 3);
+
+        for axis in [-7, 0, 1, 12] {
+            assert_eq!(test_rect.flip_horizontal(axis).flip_horizontal(axis), test_rect);
+            assert_eq!(test_rect.flip_vertical(axis).flip_vertical(axis), test_rect);
+        }
+    }
+
+    #[test]
+    fn flip_mirrors_cells() {
+        let test_rect = Rect::new(-2, 5, 4, 3);
+        let flipped = test_rect.flip_horizontal(3);
+
+        for pos in test_rect.cells() {
+            assert!(flipped.contains(Point::new(6 - pos.x, pos.y)));
+        }
+
+        let flipped = test_rect.flip_vertical(-1);
+
+        for pos in test_rect.cells() {
+            assert!(flipped.contains(Point::new(pos.x, -2 - pos.y)));
+        }
     }
-}
 
-/// An iterator over the cells inside a rect.
-/// Iterates top to bottom, left to right.
-#[derive(Clone, Debug)]
-pub struct InteriorIter {
-    cur_pos: Point,
-    rect: Rect,
-    end: bool,
-}
+    #[test]
+    fn split_clean() {
+        let test_rect = Rect::new(-2, 3, 6, 4);
+        let (top, bottom) = test_rect.split_horizontal(1).unwrap();
+        let (left, right) = test_rect.split_vertical(0).unwrap();
 
-impl Iterator for InteriorIter {
-    type Item = Point;
+        assert_eq!(top, Rect::new(-2, 3, 6, 2));
+        assert_eq!(bottom, Rect::new(-2, 1, 6, 2));
+        assert_eq!(top.union(&bottom), test_rect);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.end {
-            return None;
-        }
+        assert_eq!(left, Rect::new(-2, 3, 2, 4));
+        assert_eq!(right, Rect::new(0, 3, 4, 4));
+        assert_eq!(left.union(&right), test_rect);
+    }
 
-        let ret = self.cur_pos;
+    #[test]
+    fn split_out_of_range() {
+        let test_rect = Rect::new(-2, 3, 6, 4);
 
-        let mut new_x = self.cur_pos.x + 1;
+        assert_eq!(test_rect.split_horizontal(3), None);
+        assert_eq!(test_rect.split_horizontal(-1), None);
+        assert!(test_rect.split_horizontal(0).is_some());
+        assert_eq!(test_rect.split_vertical(-2), None);
+        assert_eq!(test_rect.split_vertical(4), None);
+        assert!(test_rect.split_vertical(3).is_some());
+    }
 
-        // Check if end of row.
-        if new_x > self.rect.right() {
-            new_x = self.rect.left;
-            self.cur_pos.y -= 1;
+    #[test]
+    fn subdivide_tiles() {
+        let test_rect = Rect::new(-3, 6, 11, 7);
+        let parts = test_rect.subdivide(3, 4);
 
-            // Check if end of rectangle.
-            if self.cur_pos.y < self.rect.bottom() {
-                self.end = true;
-            } else {
-                self.cur_pos.x = new_x;
+        assert_eq!(parts.len(), 12);
+        assert_eq!(parts.iter().map(Rect::area).sum::<u32>(), test_rect.area());
+
+        for (i, part) in parts.iter().enumerate() {
+            assert!(test_rect.contains_rect(part));
+
+            for other in &parts[i + 1..] {
+                assert!(!part.overlaps(other), "{part} overlaps {other}");
             }
         }
 
-        self.cur_pos.x = new_x;
+        // The remainder goes to the leftmost columns and topmost rows.
+        assert_eq!(parts[0], Rect::new(-3, 6, 4, 2));
+        assert_eq!(parts[2], Rect::new(5, 6, 3, 2));
+        assert_eq!(parts[11], Rect::new(5, 0, 3, 1));
+    }
 
-        Some(ret)
+    #[test]
+    fn subdivide_zero() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        assert!(test_rect.subdivide(0, 2).is_empty());
+        assert!(test_rect.subdivide(2, 0).is_empty());
     }
-}
 
-impl From<Rect> for InteriorIter {
-    fn from(val: Rect) -> Self {
-        Self {
-            cur_pos: val.top_left(),
-            rect: val,
-            end: false,
-        }
+    #[test]
+    fn subdivide_too_many() {
+        let test_rect = Rect::new(0, 0, 4, 3);
+
+        assert_eq!(test_rect.subdivide(4, 3).len(), 12);
+        assert!(test_rect.subdivide(5, 1).is_empty());
+        assert!(test_rect.subdivide(1, 4).is_empty());
+        assert!(test_rect.subdivide(u32::MAX, 1).is_empty());
+        assert!(test_rect.subdivide(50_000, 50_000).is_empty());
+        assert!(Rect::new(0, 0, -4, 3).subdivide(1, 1).is_empty());
     }
-}
 
-/// An iterator over the cells along the edges of a rect clockwise, starting from the top left.
-/// Terminates once it reaches the top left corner for the second time.
-#[derive(Clone, Debug)]
-pub struct EdgeIter {
-    cur_pos: Point,
-	dir: Point,
-	corners: [Point; 4],
-	// Number of corners reached.
-	count: u8,
-	end: bool,
-}
+    #[test]
+    fn rows_cover_rect() {
+        let test_rect = Rect::new(-4, 2, 3, 6);
+        let rows = test_rect.rows().collect::<Vec<Rect>>();
 
-impl Iterator for EdgeIter {
-    type Item = Point;
+        assert_eq!(rows.len() as i32, test_rect.hgt);
+        assert!(rows.iter().all(|row| row.hgt == 1 && row.wid == test_rect.wid));
+        assert_eq!(rows.iter().fold(rows[0], |acc, row| acc.union(row)), test_rect);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-		if self.end {
-			return None;
-		}
-		
-        let ret = self.cur_pos;
-		
-		self.cur_pos = self.cur_pos + self.dir;
-		
-		if self.corners.contains(&self.cur_pos) {
-			if self.count == 3 {
-				self.end = true;
-			}
-			self.dir.rotate_90_cw_ip();
-			self.count += 1;
-		}
+    #[test]
+    fn columns_cover_rect() {
+        let test_rect = Rect::new(-4, 2, 5, 3);
+        let columns = test_rect.columns().collect::<Vec<Rect>>();
 
-        Some(ret)
+        assert_eq!(columns.len() as i32, test_rect.wid);
+        assert!(columns.iter().all(|column| column.wid == 1 && column.hgt == test_rect.hgt));
+        assert_eq!(columns.iter().fold(columns[0], |acc, column| acc.union(column)), test_rect);
+        assert_eq!(Rect::new(0, 0, -2, 3).columns().count(), 0);
     }
-}
 
-impl From<&Rect> for EdgeIter {
-    fn from(val: &Rect) -> Self {
-        Self {
-            cur_pos: val.top_left(),
-			dir: Point::new(1, 0),
-			corners: val.corners(),
-			count: 0,
-			end: false,
+    #[test]
+    fn empty_contains_nothing() {
+        for test_rect in [Rect::new(0, 0, 0, 0), Rect::new(0, 0, 0, 4), Rect::new(0, 0, 4, -2), Rect::new(2, 2, -3, -3)] {
+            assert!(test_rect.is_empty());
+            assert!(!test_rect.is_valid());
+
+            for x in -5..=5 {
+                for y in -5..=5 {
+                    assert!(!test_rect.contains(Point::new(x, y)), "{test_rect} contains ({x}, {y})");
+                }
+            }
         }
     }
-}
 
-#[cfg(test)]
-mod unittests {
-    use super::*;
+    #[test]
+    fn normalized_negative_width() {
+        let test_rect = Rect::new(0, 0, -4, 3).normalized();
+
+        assert_eq!(test_rect, Rect::new(-4, 0, 4, 3));
+        assert_eq!(test_rect.right(), -1);
+    }
 
     #[test]
-    fn cells_test() {
-        let test_rect = Rect::new(1, 2, 3, 4);
-        let mut expected = Vec::new();
+    fn normalized_negative_height() {
+        let test_rect = Rect::new(0, 0, 4, -3).normalized();
 
-        for y in (test_rect.bottom()..=test_rect.top).rev() {
-            for x in test_rect.left..=test_rect.right() {
-                expected.push(Point::new(x, y));
+        assert_eq!(test_rect, Rect::new(0, 3, 4, 3));
+        assert_eq!(test_rect.bottom(), 1);
+    }
+
+    #[test]
+    fn normalized_both_negative() {
+        let test_rect = Rect::new(1, -1, -2, -5).normalized();
+
+        assert_eq!(test_rect, Rect::new(-1, 4, 2, 5));
+        assert_eq!(test_rect.normalized(), test_rect);
+        assert_eq!(test_rect.area(), 10);
+    }
+
+    #[test]
+    fn overlaps_zero_width() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+        let empty = Rect::new(2, -1, 0, 2);
+
+        assert!(!empty.overlaps(&test_rect));
+        assert!(!test_rect.overlaps(&empty));
+    }
+
+    #[test]
+    fn overlaps_zero_area() {
+        let empty = Rect::new(3, 3, 0, 0);
+
+        assert!(!empty.overlaps(&empty));
+        assert!(!empty.overlaps(&Rect::new(3, 3, 0, 0)));
+    }
+
+    #[test]
+    fn overlaps_slice() {
+        let others = [Rect::new(0, 0, 3, 3), Rect::new(5, 0, 3, 3), Rect::new(0, 5, 8, 2), Rect::new(2, -2, 0, 0)];
+
+        let one = Rect::new(-2, 1, 3, 2);
+        assert!(one.any_overlaps(&others));
+        assert_eq!(one.overlapping_indices(&others), vec![0]);
+
+        let none = Rect::new(3, 2, 2, 2);
+        assert!(!none.any_overlaps(&others));
+        assert!(none.overlapping_indices(&others).is_empty());
+        assert!(!none.any_overlaps(&[]));
+
+        let several = Rect::new(1, 4, 5, 8);
+        assert!(several.any_overlaps(&others));
+        assert_eq!(several.overlapping_indices(&others), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn overlap_area_partial() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        assert_eq!(test_rect.overlap_area(&Rect::new(2, -1, 4, 4)), 6);
+    }
+
+    #[test]
+    fn overlap_area_contained() {
+        let test_rect = Rect::new(0, 0, 6, 6);
+        let inner = Rect::new(1, -1, 2, 3);
+
+        assert_eq!(test_rect.overlap_area(&inner), inner.area());
+        assert_eq!(inner.overlap_area(&test_rect), inner.area());
+    }
+
+    #[test]
+    fn overlap_area_disjoint() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        assert_eq!(test_rect.overlap_area(&Rect::new(4, 0, 4, 4)), 0);
+    }
+
+    #[test]
+    fn iou_identical() {
+        let test_rect = Rect::new(-2, 3, 5, 4);
+
+        assert_eq!(test_rect.iou(&test_rect), 1.0);
+    }
+
+    #[test]
+    fn iou_half() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        // Each rect shares half its tiles, giving 8 shared tiles out of 24.
+        assert_eq!(test_rect.iou(&Rect::new(2, 0, 4, 4)), 1.0 / 3.0);
+        assert_eq!(test_rect.iou(&Rect::new(0, 0, 2, 4)), 0.5);
+    }
+
+    #[test]
+    fn iou_disjoint() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+        let empty = Rect::new(1, -1, 0, 0);
+
+        assert_eq!(test_rect.iou(&Rect::new(4, 0, 4, 4)), 0.0);
+        assert_eq!(test_rect.iou(&empty), 0.0);
+        assert_eq!(empty.iou(&empty), 0.0);
+    }
+
+    #[test]
+    fn coverage_full() {
+        let test_rect = Rect::new(-2, 3, 5, 4);
+
+        assert_eq!(test_rect.coverage_by(&test_rect), 1.0);
+        assert_eq!(test_rect.coverage_by(&test_rect.outset(3)), 1.0);
+    }
+
+    #[test]
+    fn coverage_half() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+        let other = Rect::new(2, 0, 10, 4);
+
+        assert_eq!(test_rect.coverage_by(&other), 0.5);
+        assert_eq!(other.coverage_by(&test_rect), 0.2);
+    }
+
+    #[test]
+    fn coverage_none() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        assert_eq!(test_rect.coverage_by(&Rect::new(4, 0, 4, 4)), 0.0);
+        assert_eq!(Rect::new(1, -1, 0, 0).coverage_by(&test_rect), 0.0);
+    }
+
+    #[test]
+    fn relative_position_cardinal() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert_eq!(test_rect.relative_position(&Rect::new(2, 3, 3, 2)), RectRelation::Above);
+        assert_eq!(test_rect.relative_position(&Rect::new(-2, -3, 3, 2)), RectRelation::Below);
+        assert_eq!(test_rect.relative_position(&Rect::new(-4, 1, 3, 2)), RectRelation::Left);
+        assert_eq!(test_rect.relative_position(&Rect::new(5, -1, 3, 2)), RectRelation::Right);
+    }
+
+    #[test]
+    fn relative_position_diagonal() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert_eq!(test_rect.relative_position(&Rect::new(3, 2, 2, 2)), RectRelation::AboveRight);
+        assert_eq!(test_rect.relative_position(&Rect::new(-2, -3, 2, 2)), RectRelation::BelowLeft);
+    }
+
+    #[test]
+    fn relative_position_overlapping() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert_eq!(test_rect.relative_position(&Rect::new(2, -2, 3, 3)), RectRelation::Overlapping);
+        assert_eq!(test_rect.relative_position(&test_rect), RectRelation::Overlapping);
+    }
+
+    #[test]
+    fn touches_side() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert!(test_rect.touches(&Rect::new(3, 1, 2, 2)));
+        assert!(test_rect.touches(&Rect::new(-2, -2, 2, 2)));
+        assert!(test_rect.touches(&Rect::new(1, 3, 5, 3)));
+        assert!(test_rect.touches(&Rect::new(-1, -3, 1, 1).expanded(Point::new(4, 0))));
+    }
+
+    #[test]
+    fn touches_corner() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert!(!test_rect.touches(&Rect::new(3, 2, 2, 2)));
+        assert!(!test_rect.touches(&Rect::new(-2, -3, 2, 2)));
+    }
+
+    #[test]
+    fn touches_overlapping() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert!(!test_rect.touches(&Rect::new(2, 0, 3, 3)));
+        assert!(!test_rect.touches(&test_rect));
+    }
+
+    #[test]
+    fn touches_separated() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert!(!test_rect.touches(&Rect::new(4, 0, 3, 3)));
+        assert!(!test_rect.touches(&Rect::new(0, 5, 3, 3)));
+    }
+
+    #[test]
+    fn difference_fully_covered() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert!(test_rect.difference(&test_rect).is_empty());
+        assert!(test_rect.difference(&test_rect.outset(2)).is_empty());
+    }
+
+    #[test]
+    fn difference_partially_covered() {
+        let test_rect = Rect::new(0, 5, 6, 6);
+        let other = Rect::new(3, 3, 6, 2);
+        let shared = test_rect.intersection(&other).unwrap();
+        let parts = test_rect.difference(&other);
+
+        assert_eq!(parts.len(), 3);
+
+        for (i, part) in parts.iter().enumerate() {
+            assert!(!part.overlaps(&shared));
+
+            for later in &parts[i + 1..] {
+                assert!(!part.overlaps(later), "{part} overlaps {later}");
             }
         }
 
-        assert_eq!(expected, test_rect.cells().collect::<Vec<Point>>());
+        let covered = parts.iter().map(Rect::area).sum::<u32>() + shared.area();
+
+        assert_eq!(covered, test_rect.area());
+        assert!(test_rect.cells().all(|pos| shared.contains(pos) || parts.iter().any(|part| part.contains(pos))));
     }
-	
-	#[test]
-    fn inner_cells_test1() {
-        let test_rect = Rect::new(1, 3, 3, 3);
-        let expected = vec![Point::new(2, 2)];
 
-        assert_eq!(expected, test_rect.inner_cells().collect::<Vec<Point>>());
+    #[test]
+    fn difference_disjoint() {
+        let test_rect = Rect::new(0, 0, 3, 3);
+
+        assert_eq!(test_rect.difference(&Rect::new(3, 0, 3, 3)), vec![test_rect]);
     }
-	
-	#[test]
-    fn inner_cells_test2() {
-        let test_rect = Rect::new(1, 3, 4, 4);
-        let expected = vec![Point::new(2, 2), Point::new(3, 2), Point::new(2, 1), Point::new(3, 1)];
-		
-		for cl in test_rect.inner_cells() {
-			assert!(expected.contains(&cl), "{cl} not expected");
-		}
+
+    #[test]
+    fn add_sub_assign() {
+        let original = Rect::new(1, 1, 3, 2);
+        let mut test_rect = original;
+
+        test_rect += Point::new(4, -2);
+        assert_eq!(test_rect, Rect::new(5, -1, 3, 2));
+
+        test_rect -= Point::new(4, -2);
+        assert_eq!(test_rect, original);
+    }
+
+    #[test]
+    fn scale_two() {
+        let test_rect = Rect::new(-1, 2, 3, 4);
+
+        assert_eq!(test_rect.scale(2), Rect::new(-1, 2, 6, 8));
+        assert_eq!(test_rect.scale(2).area(), test_rect.area() * 4);
+    }
+
+    #[test]
+    fn scale_three() {
+        let test_rect = Rect::new(-1, 2, 3, 4);
+
+        assert_eq!(test_rect.scale(3), Rect::new(-1, 2, 9, 12));
+        assert_eq!(test_rect.scale_from(3, Point::new(1, 1)), Rect::new(-5, 4, 9, 12));
+    }
+
+    #[test]
+    fn perimeter_square() {
+        let test_rect = Rect::new(0, 0, 4, 4);
+
+        assert_eq!(test_rect.perimeter(), 12);
+        assert_eq!(test_rect.edges().count(), 12);
+    }
+
+    #[test]
+    fn perimeter_line() {
+        let test_rect = Rect::new(0, 0, 1, 6);
+
+        assert_eq!(test_rect.perimeter(), 6);
+        assert_eq!(test_rect.edges().count(), 6);
+    }
+
+    #[test]
+    fn perimeter_single_tile() {
+        let test_rect = Rect::new(0, 0, 1, 1);
+
+        assert_eq!(test_rect.perimeter(), 1);
+        assert_eq!(test_rect.edges().count(), 1);
+    }
+
+    #[test]
+    fn aspect_ratio_wide() {
+        let test_rect = Rect::new(0, 0, 6, 3);
+
+        assert_eq!(test_rect.aspect_ratio(), 2.0);
+        assert!(!test_rect.is_square());
+    }
+
+    #[test]
+    fn aspect_ratio_square() {
+        let test_rect = Rect::new(0, 0, 5, 5);
+
+        assert_eq!(test_rect.aspect_ratio(), 1.0);
+        assert!(test_rect.is_square());
+    }
+
+    #[test]
+    fn aspect_ratio_zero_height() {
+        assert_eq!(Rect::new(0, 0, 5, 0).aspect_ratio(), f64::INFINITY);
+    }
+
+    #[test]
+    fn diagonal_length_test() {
+        assert_eq!(Rect::new(-1, 2, 3, 4).diagonal_length(), 5.0);
+    }
+
+    #[test]
+    fn area_large() {
+        let test_rect = Rect::new(0, 0, 50000, 50000);
+
+        assert_eq!(test_rect.area(), 2_500_000_000);
+    }
+
+    #[test]
+    fn area_saturates() {
+        let test_rect = Rect::new(0, 0, i32::MAX, i32::MAX);
+
+        assert_eq!(test_rect.area(), u32::MAX);
+        assert_eq!(Rect::new(0, 0, i32::MAX, 2).area(), u32::MAX - 1);
+    }
+
+    #[test]
+    fn perimeter_large() {
+        let test_rect = Rect::new(0, 0, 1 << 30, 1 << 30);
+
+        assert_eq!(test_rect.perimeter(), u32::MAX - 3);
+        assert_eq!(test_rect.edges().len() as u32, test_rect.perimeter());
+        assert_eq!(Rect::new(0, 0, i32::MAX, i32::MAX).perimeter(), u32::MAX);
+        assert_eq!(Rect::new(0, 0, i32::MAX, 1).perimeter(), i32::MAX as u32);
     }
 }